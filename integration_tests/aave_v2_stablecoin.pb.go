@@ -47,6 +47,13 @@ type AaveV2Stablecoin struct {
 	//	*AaveV2Stablecoin_SetDepositLimit_
 	//	*AaveV2Stablecoin_SetLiquidityLimit_
 	//	*AaveV2Stablecoin_SendFees_
+	//	*AaveV2Stablecoin_Sweep_
+	//	*AaveV2Stablecoin_SetTrust_
+	//	*AaveV2Stablecoin_SetFeesDistributor_
+	//	*AaveV2Stablecoin_InitiateShutdown_
+	//	*AaveV2Stablecoin_LiftShutdown_
+	//	*AaveV2Stablecoin_TransferOwnership_
+	//	*AaveV2Stablecoin_Multicall_
 	Function isAaveV2Stablecoin_Function `protobuf_oneof:"function"`
 }
 
@@ -173,6 +180,55 @@ func (x *AaveV2Stablecoin) GetSendFees() *AaveV2Stablecoin_SendFees {
 	return nil
 }
 
+func (x *AaveV2Stablecoin) GetSweep() *AaveV2Stablecoin_Sweep {
+	if x, ok := x.GetFunction().(*AaveV2Stablecoin_Sweep_); ok {
+		return x.Sweep
+	}
+	return nil
+}
+
+func (x *AaveV2Stablecoin) GetSetTrust() *AaveV2Stablecoin_SetTrust {
+	if x, ok := x.GetFunction().(*AaveV2Stablecoin_SetTrust_); ok {
+		return x.SetTrust
+	}
+	return nil
+}
+
+func (x *AaveV2Stablecoin) GetSetFeesDistributor() *AaveV2Stablecoin_SetFeesDistributor {
+	if x, ok := x.GetFunction().(*AaveV2Stablecoin_SetFeesDistributor_); ok {
+		return x.SetFeesDistributor
+	}
+	return nil
+}
+
+func (x *AaveV2Stablecoin) GetInitiateShutdown() *AaveV2Stablecoin_InitiateShutdown {
+	if x, ok := x.GetFunction().(*AaveV2Stablecoin_InitiateShutdown_); ok {
+		return x.InitiateShutdown
+	}
+	return nil
+}
+
+func (x *AaveV2Stablecoin) GetLiftShutdown() *AaveV2Stablecoin_LiftShutdown {
+	if x, ok := x.GetFunction().(*AaveV2Stablecoin_LiftShutdown_); ok {
+		return x.LiftShutdown
+	}
+	return nil
+}
+
+func (x *AaveV2Stablecoin) GetTransferOwnership() *AaveV2Stablecoin_TransferOwnership {
+	if x, ok := x.GetFunction().(*AaveV2Stablecoin_TransferOwnership_); ok {
+		return x.TransferOwnership
+	}
+	return nil
+}
+
+func (x *AaveV2Stablecoin) GetMulticall() *AaveV2Stablecoin_Multicall {
+	if x, ok := x.GetFunction().(*AaveV2Stablecoin_Multicall_); ok {
+		return x.Multicall
+	}
+	return nil
+}
+
 type isAaveV2Stablecoin_Function interface {
 	isAaveV2Stablecoin_Function()
 }
@@ -237,6 +293,41 @@ type AaveV2Stablecoin_SendFees_ struct {
 	SendFees *AaveV2Stablecoin_SendFees `protobuf:"bytes,12,opt,name=send_fees,json=sendFees,proto3,oneof"`
 }
 
+type AaveV2Stablecoin_Sweep_ struct {
+	// Represents function `sweep(address token, address to)`
+	Sweep *AaveV2Stablecoin_Sweep `protobuf:"bytes,13,opt,name=sweep,proto3,oneof"`
+}
+
+type AaveV2Stablecoin_SetTrust_ struct {
+	// Represents function `setTrust(address position, bool trust)`
+	SetTrust *AaveV2Stablecoin_SetTrust `protobuf:"bytes,14,opt,name=set_trust,json=setTrust,proto3,oneof"`
+}
+
+type AaveV2Stablecoin_SetFeesDistributor_ struct {
+	// Represents function `setFeesDistributor(bytes32 newFeesDistributor)`
+	SetFeesDistributor *AaveV2Stablecoin_SetFeesDistributor `protobuf:"bytes,15,opt,name=set_fees_distributor,json=setFeesDistributor,proto3,oneof"`
+}
+
+type AaveV2Stablecoin_InitiateShutdown_ struct {
+	// Represents function `initiateShutdown(bool emptyPosition)`
+	InitiateShutdown *AaveV2Stablecoin_InitiateShutdown `protobuf:"bytes,16,opt,name=initiate_shutdown,json=initiateShutdown,proto3,oneof"`
+}
+
+type AaveV2Stablecoin_LiftShutdown_ struct {
+	// Represents function `liftShutdown()`
+	LiftShutdown *AaveV2Stablecoin_LiftShutdown `protobuf:"bytes,17,opt,name=lift_shutdown,json=liftShutdown,proto3,oneof"`
+}
+
+type AaveV2Stablecoin_TransferOwnership_ struct {
+	// Represents function `transferOwnership(address newOwner)`
+	TransferOwnership *AaveV2Stablecoin_TransferOwnership `protobuf:"bytes,18,opt,name=transfer_ownership,json=transferOwnership,proto3,oneof"`
+}
+
+type AaveV2Stablecoin_Multicall_ struct {
+	// Represents function `multicall(bytes[] data)`
+	Multicall *AaveV2Stablecoin_Multicall `protobuf:"bytes,19,opt,name=multicall,proto3,oneof"`
+}
+
 func (*AaveV2Stablecoin_Accrue_) isAaveV2Stablecoin_Function() {}
 
 func (*AaveV2Stablecoin_ClaimAndUnstake_) isAaveV2Stablecoin_Function() {}
@@ -261,6 +352,20 @@ func (*AaveV2Stablecoin_SetLiquidityLimit_) isAaveV2Stablecoin_Function() {}
 
 func (*AaveV2Stablecoin_SendFees_) isAaveV2Stablecoin_Function() {}
 
+func (*AaveV2Stablecoin_Sweep_) isAaveV2Stablecoin_Function() {}
+
+func (*AaveV2Stablecoin_SetTrust_) isAaveV2Stablecoin_Function() {}
+
+func (*AaveV2Stablecoin_SetFeesDistributor_) isAaveV2Stablecoin_Function() {}
+
+func (*AaveV2Stablecoin_InitiateShutdown_) isAaveV2Stablecoin_Function() {}
+
+func (*AaveV2Stablecoin_LiftShutdown_) isAaveV2Stablecoin_Function() {}
+
+func (*AaveV2Stablecoin_TransferOwnership_) isAaveV2Stablecoin_Function() {}
+
+func (*AaveV2Stablecoin_Multicall_) isAaveV2Stablecoin_Function() {}
+
 //
 // Accrue yield, platform fees, and performance fees..
 //
@@ -855,6 +960,378 @@ func (*AaveV2Stablecoin_SendFees) Descriptor() ([]byte, []int) {
 	return file_aave_v2_stablecoin_proto_rawDescGZIP(), []int{0, 11}
 }
 
+//
+// Sweep tokens sent to the cellar by mistake. Cannot sweep protected assets such as the cellar asset.
+//
+// Represents function `sweep(address token, address to)`
+type AaveV2Stablecoin_Sweep struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Address of the token to sweep
+	Token string `protobuf:"bytes,1,opt,name=token,proto3" json:"token,omitempty"`
+	// Address to send the swept tokens to. Cannot be the zero address.
+	To string `protobuf:"bytes,2,opt,name=to,proto3" json:"to,omitempty"`
+}
+
+func (x *AaveV2Stablecoin_Sweep) Reset() {
+	*x = AaveV2Stablecoin_Sweep{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[13]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AaveV2Stablecoin_Sweep) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AaveV2Stablecoin_Sweep) ProtoMessage() {}
+
+func (x *AaveV2Stablecoin_Sweep) ProtoReflect() protoreflect.Message {
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[13]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AaveV2Stablecoin_Sweep.ProtoReflect.Descriptor instead.
+func (*AaveV2Stablecoin_Sweep) Descriptor() ([]byte, []int) {
+	return file_aave_v2_stablecoin_proto_rawDescGZIP(), []int{0, 12}
+}
+
+func (x *AaveV2Stablecoin_Sweep) GetToken() string {
+	if x != nil {
+		return x.Token
+	}
+	return ""
+}
+
+func (x *AaveV2Stablecoin_Sweep) GetTo() string {
+	if x != nil {
+		return x.To
+	}
+	return ""
+}
+
+//
+// Trust or distrust a position the cellar may rebalance into.
+//
+// Represents function `setTrust(address position, bool trust)`
+type AaveV2Stablecoin_SetTrust struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Address of the position token
+	Position string `protobuf:"bytes,1,opt,name=position,proto3" json:"position,omitempty"`
+	// Whether to trust the position
+	Trust bool `protobuf:"varint,2,opt,name=trust,proto3" json:"trust,omitempty"`
+}
+
+func (x *AaveV2Stablecoin_SetTrust) Reset() {
+	*x = AaveV2Stablecoin_SetTrust{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[14]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AaveV2Stablecoin_SetTrust) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AaveV2Stablecoin_SetTrust) ProtoMessage() {}
+
+func (x *AaveV2Stablecoin_SetTrust) ProtoReflect() protoreflect.Message {
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[14]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AaveV2Stablecoin_SetTrust.ProtoReflect.Descriptor instead.
+func (*AaveV2Stablecoin_SetTrust) Descriptor() ([]byte, []int) {
+	return file_aave_v2_stablecoin_proto_rawDescGZIP(), []int{0, 13}
+}
+
+func (x *AaveV2Stablecoin_SetTrust) GetPosition() string {
+	if x != nil {
+		return x.Position
+	}
+	return ""
+}
+
+func (x *AaveV2Stablecoin_SetTrust) GetTrust() bool {
+	if x != nil {
+		return x.Trust
+	}
+	return false
+}
+
+//
+// Set the Cosmos address platform fees are distributed to.
+//
+// Represents function `setFeesDistributor(bytes32 newFeesDistributor)`
+type AaveV2Stablecoin_SetFeesDistributor struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// The new fees distributor as a hex encoded 32 byte word, with or without a 0x prefix
+	NewFeesDistributor string `protobuf:"bytes,1,opt,name=new_fees_distributor,json=newFeesDistributor,proto3" json:"new_fees_distributor,omitempty"`
+}
+
+func (x *AaveV2Stablecoin_SetFeesDistributor) Reset() {
+	*x = AaveV2Stablecoin_SetFeesDistributor{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[15]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AaveV2Stablecoin_SetFeesDistributor) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AaveV2Stablecoin_SetFeesDistributor) ProtoMessage() {}
+
+func (x *AaveV2Stablecoin_SetFeesDistributor) ProtoReflect() protoreflect.Message {
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[15]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AaveV2Stablecoin_SetFeesDistributor.ProtoReflect.Descriptor instead.
+func (*AaveV2Stablecoin_SetFeesDistributor) Descriptor() ([]byte, []int) {
+	return file_aave_v2_stablecoin_proto_rawDescGZIP(), []int{0, 14}
+}
+
+func (x *AaveV2Stablecoin_SetFeesDistributor) GetNewFeesDistributor() string {
+	if x != nil {
+		return x.NewFeesDistributor
+	}
+	return ""
+}
+
+//
+// Shut down the cellar, preventing deposits and rebalances.
+//
+// Represents function `initiateShutdown(bool emptyPosition)`
+type AaveV2Stablecoin_InitiateShutdown struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Whether to pull all assets from the current Aave lending position in the process
+	EmptyPosition bool `protobuf:"varint,1,opt,name=empty_position,json=emptyPosition,proto3" json:"empty_position,omitempty"`
+}
+
+func (x *AaveV2Stablecoin_InitiateShutdown) Reset() {
+	*x = AaveV2Stablecoin_InitiateShutdown{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[16]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AaveV2Stablecoin_InitiateShutdown) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AaveV2Stablecoin_InitiateShutdown) ProtoMessage() {}
+
+func (x *AaveV2Stablecoin_InitiateShutdown) ProtoReflect() protoreflect.Message {
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[16]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AaveV2Stablecoin_InitiateShutdown.ProtoReflect.Descriptor instead.
+func (*AaveV2Stablecoin_InitiateShutdown) Descriptor() ([]byte, []int) {
+	return file_aave_v2_stablecoin_proto_rawDescGZIP(), []int{0, 15}
+}
+
+func (x *AaveV2Stablecoin_InitiateShutdown) GetEmptyPosition() bool {
+	if x != nil {
+		return x.EmptyPosition
+	}
+	return false
+}
+
+//
+// Restart the cellar after a shutdown.
+//
+// Represents function `liftShutdown()`
+type AaveV2Stablecoin_LiftShutdown struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+}
+
+func (x *AaveV2Stablecoin_LiftShutdown) Reset() {
+	*x = AaveV2Stablecoin_LiftShutdown{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[17]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AaveV2Stablecoin_LiftShutdown) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AaveV2Stablecoin_LiftShutdown) ProtoMessage() {}
+
+func (x *AaveV2Stablecoin_LiftShutdown) ProtoReflect() protoreflect.Message {
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[17]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AaveV2Stablecoin_LiftShutdown.ProtoReflect.Descriptor instead.
+func (*AaveV2Stablecoin_LiftShutdown) Descriptor() ([]byte, []int) {
+	return file_aave_v2_stablecoin_proto_rawDescGZIP(), []int{0, 16}
+}
+
+//
+// Transfer ownership of the cellar.
+//
+// Represents function `transferOwnership(address newOwner)`
+type AaveV2Stablecoin_TransferOwnership struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// Address of the new owner. Cannot be the zero address.
+	NewOwner string `protobuf:"bytes,1,opt,name=new_owner,json=newOwner,proto3" json:"new_owner,omitempty"`
+}
+
+func (x *AaveV2Stablecoin_TransferOwnership) Reset() {
+	*x = AaveV2Stablecoin_TransferOwnership{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[18]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AaveV2Stablecoin_TransferOwnership) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AaveV2Stablecoin_TransferOwnership) ProtoMessage() {}
+
+func (x *AaveV2Stablecoin_TransferOwnership) ProtoReflect() protoreflect.Message {
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[18]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AaveV2Stablecoin_TransferOwnership.ProtoReflect.Descriptor instead.
+func (*AaveV2Stablecoin_TransferOwnership) Descriptor() ([]byte, []int) {
+	return file_aave_v2_stablecoin_proto_rawDescGZIP(), []int{0, 17}
+}
+
+func (x *AaveV2Stablecoin_TransferOwnership) GetNewOwner() string {
+	if x != nil {
+		return x.NewOwner
+	}
+	return ""
+}
+
+//
+// Execute several function calls on the cellar atomically, in order, in a single transaction.
+//
+// Represents function `multicall(bytes[] data)`
+type AaveV2Stablecoin_Multicall struct {
+	state         protoimpl.MessageState
+	sizeCache     protoimpl.SizeCache
+	unknownFields protoimpl.UnknownFields
+
+	// The function calls to execute, in order. Each must set a function other than multicall.
+	Calls []*AaveV2Stablecoin `protobuf:"bytes,1,rep,name=calls,proto3" json:"calls,omitempty"`
+}
+
+func (x *AaveV2Stablecoin_Multicall) Reset() {
+	*x = AaveV2Stablecoin_Multicall{}
+	if protoimpl.UnsafeEnabled {
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[19]
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		ms.StoreMessageInfo(mi)
+	}
+}
+
+func (x *AaveV2Stablecoin_Multicall) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*AaveV2Stablecoin_Multicall) ProtoMessage() {}
+
+func (x *AaveV2Stablecoin_Multicall) ProtoReflect() protoreflect.Message {
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[19]
+	if protoimpl.UnsafeEnabled && x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use AaveV2Stablecoin_Multicall.ProtoReflect.Descriptor instead.
+func (*AaveV2Stablecoin_Multicall) Descriptor() ([]byte, []int) {
+	return file_aave_v2_stablecoin_proto_rawDescGZIP(), []int{0, 18}
+}
+
+func (x *AaveV2Stablecoin_Multicall) GetCalls() []*AaveV2Stablecoin {
+	if x != nil {
+		return x.Calls
+	}
+	return nil
+}
+
 //
 // Represents parameters for a single swap. Each swap needs the indeces in Rebalance.route of the in/out token addresses and the swap type. See the Curve contract linked above for more detail.
 type AaveV2Stablecoin_Rebalance_SwapParams struct {
@@ -878,7 +1355,7 @@ type AaveV2Stablecoin_Rebalance_SwapParams struct {
 func (x *AaveV2Stablecoin_Rebalance_SwapParams) Reset() {
 	*x = AaveV2Stablecoin_Rebalance_SwapParams{}
 	if protoimpl.UnsafeEnabled {
-		mi := &file_aave_v2_stablecoin_proto_msgTypes[13]
+		mi := &file_aave_v2_stablecoin_proto_msgTypes[20]
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		ms.StoreMessageInfo(mi)
 	}
@@ -891,7 +1368,7 @@ func (x *AaveV2Stablecoin_Rebalance_SwapParams) String() string {
 func (*AaveV2Stablecoin_Rebalance_SwapParams) ProtoMessage() {}
 
 func (x *AaveV2Stablecoin_Rebalance_SwapParams) ProtoReflect() protoreflect.Message {
-	mi := &file_aave_v2_stablecoin_proto_msgTypes[13]
+	mi := &file_aave_v2_stablecoin_proto_msgTypes[20]
 	if protoimpl.UnsafeEnabled && x != nil {
 		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
 		if ms.LoadMessageInfo() == nil {
@@ -933,7 +1410,7 @@ var File_aave_v2_stablecoin_proto protoreflect.FileDescriptor
 var file_aave_v2_stablecoin_proto_rawDesc = []byte{
 	0x0a, 0x18, 0x61, 0x61, 0x76, 0x65, 0x5f, 0x76, 0x32, 0x5f, 0x73, 0x74, 0x61, 0x62, 0x6c, 0x65,
 	0x63, 0x6f, 0x69, 0x6e, 0x2e, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x12, 0x0a, 0x73, 0x74, 0x65, 0x77,
-	0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x22, 0xaf, 0x0d, 0x0a, 0x10, 0x41, 0x61, 0x76, 0x65, 0x56,
+	0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x22, 0xe2, 0x14, 0x0a, 0x10, 0x41, 0x61, 0x76, 0x65, 0x56,
 	0x32, 0x53, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x12, 0x3d, 0x0a, 0x06, 0x61,
 	0x63, 0x63, 0x72, 0x75, 0x65, 0x18, 0x01, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x23, 0x2e, 0x73, 0x74,
 	0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x41, 0x61, 0x76, 0x65, 0x56, 0x32, 0x53,
@@ -1000,7 +1477,43 @@ var file_aave_v2_stablecoin_proto_rawDesc = []byte{
 	0x0b, 0x32, 0x25, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x41,
 	0x61, 0x76, 0x65, 0x56, 0x32, 0x53, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x2e,
 	0x53, 0x65, 0x6e, 0x64, 0x46, 0x65, 0x65, 0x73, 0x48, 0x00, 0x52, 0x08, 0x73, 0x65, 0x6e, 0x64,
-	0x46, 0x65, 0x65, 0x73, 0x1a, 0x08, 0x0a, 0x06, 0x41, 0x63, 0x63, 0x72, 0x75, 0x65, 0x1a, 0x11,
+	0x46, 0x65, 0x65, 0x73, 0x12, 0x3a, 0x0a, 0x05, 0x73, 0x77, 0x65, 0x65, 0x70, 0x18, 0x0d, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x22, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32,
+	0x2e, 0x41, 0x61, 0x76, 0x65, 0x56, 0x32, 0x53, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69,
+	0x6e, 0x2e, 0x53, 0x77, 0x65, 0x65, 0x70, 0x48, 0x00, 0x52, 0x05, 0x73, 0x77, 0x65, 0x65, 0x70,
+	0x12, 0x44, 0x0a, 0x09, 0x73, 0x65, 0x74, 0x5f, 0x74, 0x72, 0x75, 0x73, 0x74, 0x18, 0x0e, 0x20,
+	0x01, 0x28, 0x0b, 0x32, 0x25, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32,
+	0x2e, 0x41, 0x61, 0x76, 0x65, 0x56, 0x32, 0x53, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69,
+	0x6e, 0x2e, 0x53, 0x65, 0x74, 0x54, 0x72, 0x75, 0x73, 0x74, 0x48, 0x00, 0x52, 0x08, 0x73, 0x65,
+	0x74, 0x54, 0x72, 0x75, 0x73, 0x74, 0x12, 0x63, 0x0a, 0x14, 0x73, 0x65, 0x74, 0x5f, 0x66, 0x65,
+	0x65, 0x73, 0x5f, 0x64, 0x69, 0x73, 0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x6f, 0x72, 0x18, 0x0f,
+	0x20, 0x01, 0x28, 0x0b, 0x32, 0x2f, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76,
+	0x32, 0x2e, 0x41, 0x61, 0x76, 0x65, 0x56, 0x32, 0x53, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f,
+	0x69, 0x6e, 0x2e, 0x53, 0x65, 0x74, 0x46, 0x65, 0x65, 0x73, 0x44, 0x69, 0x73, 0x74, 0x72, 0x69,
+	0x62, 0x75, 0x74, 0x6f, 0x72, 0x48, 0x00, 0x52, 0x12, 0x73, 0x65, 0x74, 0x46, 0x65, 0x65, 0x73,
+	0x44, 0x69, 0x73, 0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x6f, 0x72, 0x12, 0x5c, 0x0a, 0x11, 0x69,
+	0x6e, 0x69, 0x74, 0x69, 0x61, 0x74, 0x65, 0x5f, 0x73, 0x68, 0x75, 0x74, 0x64, 0x6f, 0x77, 0x6e,
+	0x18, 0x10, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2d, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64,
+	0x2e, 0x76, 0x32, 0x2e, 0x41, 0x61, 0x76, 0x65, 0x56, 0x32, 0x53, 0x74, 0x61, 0x62, 0x6c, 0x65,
+	0x63, 0x6f, 0x69, 0x6e, 0x2e, 0x49, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x74, 0x65, 0x53, 0x68, 0x75,
+	0x74, 0x64, 0x6f, 0x77, 0x6e, 0x48, 0x00, 0x52, 0x10, 0x69, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x74,
+	0x65, 0x53, 0x68, 0x75, 0x74, 0x64, 0x6f, 0x77, 0x6e, 0x12, 0x50, 0x0a, 0x0d, 0x6c, 0x69, 0x66,
+	0x74, 0x5f, 0x73, 0x68, 0x75, 0x74, 0x64, 0x6f, 0x77, 0x6e, 0x18, 0x11, 0x20, 0x01, 0x28, 0x0b,
+	0x32, 0x29, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x41, 0x61,
+	0x76, 0x65, 0x56, 0x32, 0x53, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x2e, 0x4c,
+	0x69, 0x66, 0x74, 0x53, 0x68, 0x75, 0x74, 0x64, 0x6f, 0x77, 0x6e, 0x48, 0x00, 0x52, 0x0c, 0x6c,
+	0x69, 0x66, 0x74, 0x53, 0x68, 0x75, 0x74, 0x64, 0x6f, 0x77, 0x6e, 0x12, 0x5f, 0x0a, 0x12, 0x74,
+	0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x5f, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69,
+	0x70, 0x18, 0x12, 0x20, 0x01, 0x28, 0x0b, 0x32, 0x2e, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72,
+	0x64, 0x2e, 0x76, 0x32, 0x2e, 0x41, 0x61, 0x76, 0x65, 0x56, 0x32, 0x53, 0x74, 0x61, 0x62, 0x6c,
+	0x65, 0x63, 0x6f, 0x69, 0x6e, 0x2e, 0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x4f, 0x77,
+	0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x48, 0x00, 0x52, 0x11, 0x74, 0x72, 0x61, 0x6e, 0x73,
+	0x66, 0x65, 0x72, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69, 0x70, 0x12, 0x46, 0x0a, 0x09,
+	0x6d, 0x75, 0x6c, 0x74, 0x69, 0x63, 0x61, 0x6c, 0x6c, 0x18, 0x13, 0x20, 0x01, 0x28, 0x0b, 0x32,
+	0x26, 0x2e, 0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x41, 0x61, 0x76,
+	0x65, 0x56, 0x32, 0x53, 0x74, 0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x2e, 0x4d, 0x75,
+	0x6c, 0x74, 0x69, 0x63, 0x61, 0x6c, 0x6c, 0x48, 0x00, 0x52, 0x09, 0x6d, 0x75, 0x6c, 0x74, 0x69,
+	0x63, 0x61, 0x6c, 0x6c, 0x1a, 0x08, 0x0a, 0x06, 0x41, 0x63, 0x63, 0x72, 0x75, 0x65, 0x1a, 0x11,
 	0x0a, 0x0f, 0x43, 0x6c, 0x61, 0x69, 0x6d, 0x41, 0x6e, 0x64, 0x55, 0x6e, 0x73, 0x74, 0x61, 0x6b,
 	0x65, 0x1a, 0x0f, 0x0a, 0x0d, 0x45, 0x6e, 0x74, 0x65, 0x72, 0x50, 0x6f, 0x73, 0x69, 0x74, 0x69,
 	0x6f, 0x6e, 0x1a, 0x31, 0x0a, 0x17, 0x45, 0x6e, 0x74, 0x65, 0x72, 0x50, 0x6f, 0x73, 0x69, 0x74,
@@ -1039,10 +1552,33 @@ var file_aave_v2_stablecoin_proto_rawDesc = []byte{
 	0x6c, 0x69, 0x6d, 0x69, 0x74, 0x1a, 0x29, 0x0a, 0x11, 0x53, 0x65, 0x74, 0x4c, 0x69, 0x71, 0x75,
 	0x69, 0x64, 0x69, 0x74, 0x79, 0x4c, 0x69, 0x6d, 0x69, 0x74, 0x12, 0x14, 0x0a, 0x05, 0x6c, 0x69,
 	0x6d, 0x69, 0x74, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x6c, 0x69, 0x6d, 0x69, 0x74,
-	0x1a, 0x0a, 0x0a, 0x08, 0x53, 0x65, 0x6e, 0x64, 0x46, 0x65, 0x65, 0x73, 0x42, 0x0a, 0x0a, 0x08,
-	0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x42, 0x10, 0x5a, 0x0e, 0x2f, 0x73, 0x74, 0x65,
-	0x77, 0x61, 0x72, 0x64, 0x5f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x06, 0x70, 0x72, 0x6f, 0x74,
-	0x6f, 0x33,
+	0x1a, 0x0a, 0x0a, 0x08, 0x53, 0x65, 0x6e, 0x64, 0x46, 0x65, 0x65, 0x73, 0x1a, 0x2d, 0x0a, 0x05,
+	0x53, 0x77, 0x65, 0x65, 0x70, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x05, 0x74, 0x6f, 0x6b, 0x65, 0x6e, 0x12, 0x0e, 0x0a, 0x02, 0x74,
+	0x6f, 0x18, 0x02, 0x20, 0x01, 0x28, 0x09, 0x52, 0x02, 0x74, 0x6f, 0x1a, 0x3c, 0x0a, 0x08, 0x53,
+	0x65, 0x74, 0x54, 0x72, 0x75, 0x73, 0x74, 0x12, 0x1a, 0x0a, 0x08, 0x70, 0x6f, 0x73, 0x69, 0x74,
+	0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x70, 0x6f, 0x73, 0x69, 0x74,
+	0x69, 0x6f, 0x6e, 0x12, 0x14, 0x0a, 0x05, 0x74, 0x72, 0x75, 0x73, 0x74, 0x18, 0x02, 0x20, 0x01,
+	0x28, 0x08, 0x52, 0x05, 0x74, 0x72, 0x75, 0x73, 0x74, 0x1a, 0x46, 0x0a, 0x12, 0x53, 0x65, 0x74,
+	0x46, 0x65, 0x65, 0x73, 0x44, 0x69, 0x73, 0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x6f, 0x72, 0x12,
+	0x30, 0x0a, 0x14, 0x6e, 0x65, 0x77, 0x5f, 0x66, 0x65, 0x65, 0x73, 0x5f, 0x64, 0x69, 0x73, 0x74,
+	0x72, 0x69, 0x62, 0x75, 0x74, 0x6f, 0x72, 0x18, 0x01, 0x20, 0x01, 0x28, 0x09, 0x52, 0x12, 0x6e,
+	0x65, 0x77, 0x46, 0x65, 0x65, 0x73, 0x44, 0x69, 0x73, 0x74, 0x72, 0x69, 0x62, 0x75, 0x74, 0x6f,
+	0x72, 0x1a, 0x39, 0x0a, 0x10, 0x49, 0x6e, 0x69, 0x74, 0x69, 0x61, 0x74, 0x65, 0x53, 0x68, 0x75,
+	0x74, 0x64, 0x6f, 0x77, 0x6e, 0x12, 0x25, 0x0a, 0x0e, 0x65, 0x6d, 0x70, 0x74, 0x79, 0x5f, 0x70,
+	0x6f, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x18, 0x01, 0x20, 0x01, 0x28, 0x08, 0x52, 0x0d, 0x65,
+	0x6d, 0x70, 0x74, 0x79, 0x50, 0x6f, 0x73, 0x69, 0x74, 0x69, 0x6f, 0x6e, 0x1a, 0x0e, 0x0a, 0x0c,
+	0x4c, 0x69, 0x66, 0x74, 0x53, 0x68, 0x75, 0x74, 0x64, 0x6f, 0x77, 0x6e, 0x1a, 0x30, 0x0a, 0x11,
+	0x54, 0x72, 0x61, 0x6e, 0x73, 0x66, 0x65, 0x72, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x73, 0x68, 0x69,
+	0x70, 0x12, 0x1b, 0x0a, 0x09, 0x6e, 0x65, 0x77, 0x5f, 0x6f, 0x77, 0x6e, 0x65, 0x72, 0x18, 0x01,
+	0x20, 0x01, 0x28, 0x09, 0x52, 0x08, 0x6e, 0x65, 0x77, 0x4f, 0x77, 0x6e, 0x65, 0x72, 0x1a, 0x3f,
+	0x0a, 0x09, 0x4d, 0x75, 0x6c, 0x74, 0x69, 0x63, 0x61, 0x6c, 0x6c, 0x12, 0x32, 0x0a, 0x05, 0x63,
+	0x61, 0x6c, 0x6c, 0x73, 0x18, 0x01, 0x20, 0x03, 0x28, 0x0b, 0x32, 0x1c, 0x2e, 0x73, 0x74, 0x65,
+	0x77, 0x61, 0x72, 0x64, 0x2e, 0x76, 0x32, 0x2e, 0x41, 0x61, 0x76, 0x65, 0x56, 0x32, 0x53, 0x74,
+	0x61, 0x62, 0x6c, 0x65, 0x63, 0x6f, 0x69, 0x6e, 0x52, 0x05, 0x63, 0x61, 0x6c, 0x6c, 0x73, 0x42,
+	0x0a, 0x0a, 0x08, 0x66, 0x75, 0x6e, 0x63, 0x74, 0x69, 0x6f, 0x6e, 0x42, 0x10, 0x5a, 0x0e, 0x2f,
+	0x73, 0x74, 0x65, 0x77, 0x61, 0x72, 0x64, 0x5f, 0x70, 0x72, 0x6f, 0x74, 0x6f, 0x62, 0x06, 0x70,
+	0x72, 0x6f, 0x74, 0x6f, 0x33,
 }
 
 var (
@@ -1057,7 +1593,7 @@ func file_aave_v2_stablecoin_proto_rawDescGZIP() []byte {
 	return file_aave_v2_stablecoin_proto_rawDescData
 }
 
-var file_aave_v2_stablecoin_proto_msgTypes = make([]protoimpl.MessageInfo, 14)
+var file_aave_v2_stablecoin_proto_msgTypes = make([]protoimpl.MessageInfo, 21)
 var file_aave_v2_stablecoin_proto_goTypes = []interface{}{
 	(*AaveV2Stablecoin)(nil),                         // 0: steward.v2.AaveV2Stablecoin
 	(*AaveV2Stablecoin_Accrue)(nil),                  // 1: steward.v2.AaveV2Stablecoin.Accrue
@@ -1072,7 +1608,14 @@ var file_aave_v2_stablecoin_proto_goTypes = []interface{}{
 	(*AaveV2Stablecoin_SetDepositLimit)(nil),         // 10: steward.v2.AaveV2Stablecoin.SetDepositLimit
 	(*AaveV2Stablecoin_SetLiquidityLimit)(nil),       // 11: steward.v2.AaveV2Stablecoin.SetLiquidityLimit
 	(*AaveV2Stablecoin_SendFees)(nil),                // 12: steward.v2.AaveV2Stablecoin.SendFees
-	(*AaveV2Stablecoin_Rebalance_SwapParams)(nil),    // 13: steward.v2.AaveV2Stablecoin.Rebalance.SwapParams
+	(*AaveV2Stablecoin_Sweep)(nil),                   // 13: steward.v2.AaveV2Stablecoin.Sweep
+	(*AaveV2Stablecoin_SetTrust)(nil),                // 14: steward.v2.AaveV2Stablecoin.SetTrust
+	(*AaveV2Stablecoin_SetFeesDistributor)(nil),      // 15: steward.v2.AaveV2Stablecoin.SetFeesDistributor
+	(*AaveV2Stablecoin_InitiateShutdown)(nil),        // 16: steward.v2.AaveV2Stablecoin.InitiateShutdown
+	(*AaveV2Stablecoin_LiftShutdown)(nil),            // 17: steward.v2.AaveV2Stablecoin.LiftShutdown
+	(*AaveV2Stablecoin_TransferOwnership)(nil),       // 18: steward.v2.AaveV2Stablecoin.TransferOwnership
+	(*AaveV2Stablecoin_Multicall)(nil),               // 19: steward.v2.AaveV2Stablecoin.Multicall
+	(*AaveV2Stablecoin_Rebalance_SwapParams)(nil),    // 20: steward.v2.AaveV2Stablecoin.Rebalance.SwapParams
 }
 var file_aave_v2_stablecoin_proto_depIdxs = []int32{
 	1,  // 0: steward.v2.AaveV2Stablecoin.accrue:type_name -> steward.v2.AaveV2Stablecoin.Accrue
@@ -1087,12 +1630,20 @@ var file_aave_v2_stablecoin_proto_depIdxs = []int32{
 	10, // 9: steward.v2.AaveV2Stablecoin.set_deposit_limit:type_name -> steward.v2.AaveV2Stablecoin.SetDepositLimit
 	11, // 10: steward.v2.AaveV2Stablecoin.set_liquidity_limit:type_name -> steward.v2.AaveV2Stablecoin.SetLiquidityLimit
 	12, // 11: steward.v2.AaveV2Stablecoin.send_fees:type_name -> steward.v2.AaveV2Stablecoin.SendFees
-	13, // 12: steward.v2.AaveV2Stablecoin.Rebalance.swap_params:type_name -> steward.v2.AaveV2Stablecoin.Rebalance.SwapParams
-	13, // [13:13] is the sub-list for method output_type
-	13, // [13:13] is the sub-list for method input_type
-	13, // [13:13] is the sub-list for extension type_name
-	13, // [13:13] is the sub-list for extension extendee
-	0,  // [0:13] is the sub-list for field type_name
+	13, // 12: steward.v2.AaveV2Stablecoin.sweep:type_name -> steward.v2.AaveV2Stablecoin.Sweep
+	14, // 13: steward.v2.AaveV2Stablecoin.set_trust:type_name -> steward.v2.AaveV2Stablecoin.SetTrust
+	15, // 14: steward.v2.AaveV2Stablecoin.set_fees_distributor:type_name -> steward.v2.AaveV2Stablecoin.SetFeesDistributor
+	16, // 15: steward.v2.AaveV2Stablecoin.initiate_shutdown:type_name -> steward.v2.AaveV2Stablecoin.InitiateShutdown
+	17, // 16: steward.v2.AaveV2Stablecoin.lift_shutdown:type_name -> steward.v2.AaveV2Stablecoin.LiftShutdown
+	18, // 17: steward.v2.AaveV2Stablecoin.transfer_ownership:type_name -> steward.v2.AaveV2Stablecoin.TransferOwnership
+	19, // 18: steward.v2.AaveV2Stablecoin.multicall:type_name -> steward.v2.AaveV2Stablecoin.Multicall
+	20, // 19: steward.v2.AaveV2Stablecoin.Rebalance.swap_params:type_name -> steward.v2.AaveV2Stablecoin.Rebalance.SwapParams
+	0,  // 20: steward.v2.AaveV2Stablecoin.Multicall.calls:type_name -> steward.v2.AaveV2Stablecoin
+	21, // [21:21] is the sub-list for method output_type
+	21, // [21:21] is the sub-list for method input_type
+	21, // [21:21] is the sub-list for extension type_name
+	21, // [21:21] is the sub-list for extension extendee
+	0,  // [0:21] is the sub-list for field type_name
 }
 
 func init() { file_aave_v2_stablecoin_proto_init() }
@@ -1258,6 +1809,90 @@ func file_aave_v2_stablecoin_proto_init() {
 			}
 		}
 		file_aave_v2_stablecoin_proto_msgTypes[13].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AaveV2Stablecoin_Sweep); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_aave_v2_stablecoin_proto_msgTypes[14].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AaveV2Stablecoin_SetTrust); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_aave_v2_stablecoin_proto_msgTypes[15].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AaveV2Stablecoin_SetFeesDistributor); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_aave_v2_stablecoin_proto_msgTypes[16].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AaveV2Stablecoin_InitiateShutdown); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_aave_v2_stablecoin_proto_msgTypes[17].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AaveV2Stablecoin_LiftShutdown); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_aave_v2_stablecoin_proto_msgTypes[18].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AaveV2Stablecoin_TransferOwnership); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_aave_v2_stablecoin_proto_msgTypes[19].Exporter = func(v interface{}, i int) interface{} {
+			switch v := v.(*AaveV2Stablecoin_Multicall); i {
+			case 0:
+				return &v.state
+			case 1:
+				return &v.sizeCache
+			case 2:
+				return &v.unknownFields
+			default:
+				return nil
+			}
+		}
+		file_aave_v2_stablecoin_proto_msgTypes[20].Exporter = func(v interface{}, i int) interface{} {
 			switch v := v.(*AaveV2Stablecoin_Rebalance_SwapParams); i {
 			case 0:
 				return &v.state
@@ -1283,6 +1918,13 @@ func file_aave_v2_stablecoin_proto_init() {
 		(*AaveV2Stablecoin_SetDepositLimit_)(nil),
 		(*AaveV2Stablecoin_SetLiquidityLimit_)(nil),
 		(*AaveV2Stablecoin_SendFees_)(nil),
+		(*AaveV2Stablecoin_Sweep_)(nil),
+		(*AaveV2Stablecoin_SetTrust_)(nil),
+		(*AaveV2Stablecoin_SetFeesDistributor_)(nil),
+		(*AaveV2Stablecoin_InitiateShutdown_)(nil),
+		(*AaveV2Stablecoin_LiftShutdown_)(nil),
+		(*AaveV2Stablecoin_TransferOwnership_)(nil),
+		(*AaveV2Stablecoin_Multicall_)(nil),
 	}
 	type x struct{}
 	out := protoimpl.TypeBuilder{
@@ -1290,7 +1932,7 @@ func file_aave_v2_stablecoin_proto_init() {
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: file_aave_v2_stablecoin_proto_rawDesc,
 			NumEnums:      0,
-			NumMessages:   14,
+			NumMessages:   21,
 			NumExtensions: 0,
 			NumServices:   0,
 		},
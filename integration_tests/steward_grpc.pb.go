@@ -24,6 +24,18 @@ const _ = grpc.SupportPackageIsVersion7
 type ContractCallClient interface {
 	// Handles simple contract call submission
 	Submit(ctx context.Context, in *SubmitRequest, opts ...grpc.CallOption) (*SubmitResponse, error)
+	// Handles submission of multiple contract calls in a single transaction
+	SubmitBatch(ctx context.Context, in *SubmitBatchRequest, opts ...grpc.CallOption) (*SubmitBatchResponse, error)
+	// Handles scheduling of a contract call at a future block height
+	ScheduleCork(ctx context.Context, in *ScheduleCorkRequest, opts ...grpc.CallOption) (*ScheduleCorkResponse, error)
+	// Reports the vote and execution progress of a submitted cork
+	GetCorkStatus(ctx context.Context, in *GetCorkStatusRequest, opts ...grpc.CallOption) (*GetCorkStatusResponse, error)
+	// Lists past submissions recorded in the submission journal. Only Submit requests are journaled. With an authorization policy configured, only submissions made by the calling client are listed
+	GetSubmissionHistory(ctx context.Context, in *GetSubmissionHistoryRequest, opts ...grpc.CallOption) (*GetSubmissionHistoryResponse, error)
+	// Validates and encodes a contract call exactly as Submit would, returning the cork that would be sent without sending it
+	Preview(ctx context.Context, in *PreviewRequest, opts ...grpc.CallOption) (*PreviewResponse, error)
+	// Decodes the encoded contract call of a cork into the Cellar function it calls and its arguments
+	Decode(ctx context.Context, in *DecodeRequest, opts ...grpc.CallOption) (*DecodeResponse, error)
 }
 
 type contractCallClient struct {
@@ -43,12 +55,78 @@ func (c *contractCallClient) Submit(ctx context.Context, in *SubmitRequest, opts
 	return out, nil
 }
 
+func (c *contractCallClient) SubmitBatch(ctx context.Context, in *SubmitBatchRequest, opts ...grpc.CallOption) (*SubmitBatchResponse, error) {
+	out := new(SubmitBatchResponse)
+	err := c.cc.Invoke(ctx, "/steward.v2.ContractCall/SubmitBatch", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *contractCallClient) ScheduleCork(ctx context.Context, in *ScheduleCorkRequest, opts ...grpc.CallOption) (*ScheduleCorkResponse, error) {
+	out := new(ScheduleCorkResponse)
+	err := c.cc.Invoke(ctx, "/steward.v2.ContractCall/ScheduleCork", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *contractCallClient) GetCorkStatus(ctx context.Context, in *GetCorkStatusRequest, opts ...grpc.CallOption) (*GetCorkStatusResponse, error) {
+	out := new(GetCorkStatusResponse)
+	err := c.cc.Invoke(ctx, "/steward.v2.ContractCall/GetCorkStatus", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *contractCallClient) GetSubmissionHistory(ctx context.Context, in *GetSubmissionHistoryRequest, opts ...grpc.CallOption) (*GetSubmissionHistoryResponse, error) {
+	out := new(GetSubmissionHistoryResponse)
+	err := c.cc.Invoke(ctx, "/steward.v2.ContractCall/GetSubmissionHistory", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *contractCallClient) Preview(ctx context.Context, in *PreviewRequest, opts ...grpc.CallOption) (*PreviewResponse, error) {
+	out := new(PreviewResponse)
+	err := c.cc.Invoke(ctx, "/steward.v2.ContractCall/Preview", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *contractCallClient) Decode(ctx context.Context, in *DecodeRequest, opts ...grpc.CallOption) (*DecodeResponse, error) {
+	out := new(DecodeResponse)
+	err := c.cc.Invoke(ctx, "/steward.v2.ContractCall/Decode", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // ContractCallServer is the server API for ContractCall service.
 // All implementations must embed UnimplementedContractCallServer
 // for forward compatibility
 type ContractCallServer interface {
 	// Handles simple contract call submission
 	Submit(context.Context, *SubmitRequest) (*SubmitResponse, error)
+	// Handles submission of multiple contract calls in a single transaction
+	SubmitBatch(context.Context, *SubmitBatchRequest) (*SubmitBatchResponse, error)
+	// Handles scheduling of a contract call at a future block height
+	ScheduleCork(context.Context, *ScheduleCorkRequest) (*ScheduleCorkResponse, error)
+	// Reports the vote and execution progress of a submitted cork
+	GetCorkStatus(context.Context, *GetCorkStatusRequest) (*GetCorkStatusResponse, error)
+	// Lists past submissions recorded in the submission journal. Only Submit requests are journaled. With an authorization policy configured, only submissions made by the calling client are listed
+	GetSubmissionHistory(context.Context, *GetSubmissionHistoryRequest) (*GetSubmissionHistoryResponse, error)
+	// Validates and encodes a contract call exactly as Submit would, returning the cork that would be sent without sending it
+	Preview(context.Context, *PreviewRequest) (*PreviewResponse, error)
+	// Decodes the encoded contract call of a cork into the Cellar function it calls and its arguments
+	Decode(context.Context, *DecodeRequest) (*DecodeResponse, error)
 	mustEmbedUnimplementedContractCallServer()
 }
 
@@ -59,6 +137,24 @@ type UnimplementedContractCallServer struct {
 func (UnimplementedContractCallServer) Submit(context.Context, *SubmitRequest) (*SubmitResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method Submit not implemented")
 }
+func (UnimplementedContractCallServer) SubmitBatch(context.Context, *SubmitBatchRequest) (*SubmitBatchResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method SubmitBatch not implemented")
+}
+func (UnimplementedContractCallServer) ScheduleCork(context.Context, *ScheduleCorkRequest) (*ScheduleCorkResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method ScheduleCork not implemented")
+}
+func (UnimplementedContractCallServer) GetCorkStatus(context.Context, *GetCorkStatusRequest) (*GetCorkStatusResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetCorkStatus not implemented")
+}
+func (UnimplementedContractCallServer) GetSubmissionHistory(context.Context, *GetSubmissionHistoryRequest) (*GetSubmissionHistoryResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetSubmissionHistory not implemented")
+}
+func (UnimplementedContractCallServer) Preview(context.Context, *PreviewRequest) (*PreviewResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Preview not implemented")
+}
+func (UnimplementedContractCallServer) Decode(context.Context, *DecodeRequest) (*DecodeResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method Decode not implemented")
+}
 func (UnimplementedContractCallServer) mustEmbedUnimplementedContractCallServer() {}
 
 // UnsafeContractCallServer may be embedded to opt out of forward compatibility for this service.
@@ -90,6 +186,114 @@ func _ContractCall_Submit_Handler(srv interface{}, ctx context.Context, dec func
 	return interceptor(ctx, in, info, handler)
 }
 
+func _ContractCall_SubmitBatch_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(SubmitBatchRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ContractCallServer).SubmitBatch(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/steward.v2.ContractCall/SubmitBatch",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ContractCallServer).SubmitBatch(ctx, req.(*SubmitBatchRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _ContractCall_ScheduleCork_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(ScheduleCorkRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ContractCallServer).ScheduleCork(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/steward.v2.ContractCall/ScheduleCork",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ContractCallServer).ScheduleCork(ctx, req.(*ScheduleCorkRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _ContractCall_GetCorkStatus_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetCorkStatusRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ContractCallServer).GetCorkStatus(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/steward.v2.ContractCall/GetCorkStatus",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ContractCallServer).GetCorkStatus(ctx, req.(*GetCorkStatusRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _ContractCall_GetSubmissionHistory_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetSubmissionHistoryRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ContractCallServer).GetSubmissionHistory(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/steward.v2.ContractCall/GetSubmissionHistory",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ContractCallServer).GetSubmissionHistory(ctx, req.(*GetSubmissionHistoryRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _ContractCall_Preview_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(PreviewRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ContractCallServer).Preview(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/steward.v2.ContractCall/Preview",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ContractCallServer).Preview(ctx, req.(*PreviewRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _ContractCall_Decode_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(DecodeRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(ContractCallServer).Decode(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/steward.v2.ContractCall/Decode",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(ContractCallServer).Decode(ctx, req.(*DecodeRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // ContractCall_ServiceDesc is the grpc.ServiceDesc for ContractCall service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -101,6 +305,156 @@ var ContractCall_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "Submit",
 			Handler:    _ContractCall_Submit_Handler,
 		},
+		{
+			MethodName: "SubmitBatch",
+			Handler:    _ContractCall_SubmitBatch_Handler,
+		},
+		{
+			MethodName: "ScheduleCork",
+			Handler:    _ContractCall_ScheduleCork_Handler,
+		},
+		{
+			MethodName: "GetCorkStatus",
+			Handler:    _ContractCall_GetCorkStatus_Handler,
+		},
+		{
+			MethodName: "GetSubmissionHistory",
+			Handler:    _ContractCall_GetSubmissionHistory_Handler,
+		},
+		{
+			MethodName: "Preview",
+			Handler:    _ContractCall_Preview_Handler,
+		},
+		{
+			MethodName: "Decode",
+			Handler:    _ContractCall_Decode_Handler,
+		},
+	},
+	Streams:  []grpc.StreamDesc{},
+	Metadata: "steward.proto",
+}
+
+// CellarQueryClient is the client API for CellarQuery service.
+//
+// For semantics around ctx use and closing/ending streaming RPCs, please refer to https://pkg.go.dev/google.golang.org/grpc/?tab=doc#ClientConn.NewStream.
+type CellarQueryClient interface {
+	// Returns a snapshot of the view state of a Cellar
+	GetCellarState(ctx context.Context, in *GetCellarStateRequest, opts ...grpc.CallOption) (*GetCellarStateResponse, error)
+	// Lists the Cellar families this Steward can encode and decode calls for
+	GetAdapters(ctx context.Context, in *GetAdaptersRequest, opts ...grpc.CallOption) (*GetAdaptersResponse, error)
+}
+
+type cellarQueryClient struct {
+	cc grpc.ClientConnInterface
+}
+
+func NewCellarQueryClient(cc grpc.ClientConnInterface) CellarQueryClient {
+	return &cellarQueryClient{cc}
+}
+
+func (c *cellarQueryClient) GetCellarState(ctx context.Context, in *GetCellarStateRequest, opts ...grpc.CallOption) (*GetCellarStateResponse, error) {
+	out := new(GetCellarStateResponse)
+	err := c.cc.Invoke(ctx, "/steward.v2.CellarQuery/GetCellarState", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+func (c *cellarQueryClient) GetAdapters(ctx context.Context, in *GetAdaptersRequest, opts ...grpc.CallOption) (*GetAdaptersResponse, error) {
+	out := new(GetAdaptersResponse)
+	err := c.cc.Invoke(ctx, "/steward.v2.CellarQuery/GetAdapters", in, out, opts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
+// CellarQueryServer is the server API for CellarQuery service.
+// All implementations must embed UnimplementedCellarQueryServer
+// for forward compatibility
+type CellarQueryServer interface {
+	// Returns a snapshot of the view state of a Cellar
+	GetCellarState(context.Context, *GetCellarStateRequest) (*GetCellarStateResponse, error)
+	// Lists the Cellar families this Steward can encode and decode calls for
+	GetAdapters(context.Context, *GetAdaptersRequest) (*GetAdaptersResponse, error)
+	mustEmbedUnimplementedCellarQueryServer()
+}
+
+// UnimplementedCellarQueryServer must be embedded to have forward compatible implementations.
+type UnimplementedCellarQueryServer struct {
+}
+
+func (UnimplementedCellarQueryServer) GetCellarState(context.Context, *GetCellarStateRequest) (*GetCellarStateResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetCellarState not implemented")
+}
+func (UnimplementedCellarQueryServer) GetAdapters(context.Context, *GetAdaptersRequest) (*GetAdaptersResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method GetAdapters not implemented")
+}
+func (UnimplementedCellarQueryServer) mustEmbedUnimplementedCellarQueryServer() {}
+
+// UnsafeCellarQueryServer may be embedded to opt out of forward compatibility for this service.
+// Use of this interface is not recommended, as added methods to CellarQueryServer will
+// result in compilation errors.
+type UnsafeCellarQueryServer interface {
+	mustEmbedUnimplementedCellarQueryServer()
+}
+
+func RegisterCellarQueryServer(s grpc.ServiceRegistrar, srv CellarQueryServer) {
+	s.RegisterService(&CellarQuery_ServiceDesc, srv)
+}
+
+func _CellarQuery_GetCellarState_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetCellarStateRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(CellarQueryServer).GetCellarState(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/steward.v2.CellarQuery/GetCellarState",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(CellarQueryServer).GetCellarState(ctx, req.(*GetCellarStateRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+func _CellarQuery_GetAdapters_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(GetAdaptersRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(CellarQueryServer).GetAdapters(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: "/steward.v2.CellarQuery/GetAdapters",
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(CellarQueryServer).GetAdapters(ctx, req.(*GetAdaptersRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
+// CellarQuery_ServiceDesc is the grpc.ServiceDesc for CellarQuery service.
+// It's only intended for direct use with grpc.RegisterService,
+// and not to be introspected or modified (even as a copy)
+var CellarQuery_ServiceDesc = grpc.ServiceDesc{
+	ServiceName: "steward.v2.CellarQuery",
+	HandlerType: (*CellarQueryServer)(nil),
+	Methods: []grpc.MethodDesc{
+		{
+			MethodName: "GetCellarState",
+			Handler:    _CellarQuery_GetCellarState_Handler,
+		},
+		{
+			MethodName: "GetAdapters",
+			Handler:    _CellarQuery_GetAdapters_Handler,
+		},
 	},
 	Streams:  []grpc.StreamDesc{},
 	Metadata: "steward.proto",
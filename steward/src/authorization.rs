@@ -0,0 +1,211 @@
+//! Per-client authorization of contract calls. Clients are identified by the certificate they
+//! present to the server, and a policy file maps each identity to the cellars and functions it may
+//! call. Without a policy file any client trusted by the server's client CA may call any approved
+//! cellar.
+use crate::{
+    error::{Error, ErrorKind},
+    prelude::APP,
+};
+use abscissa_core::{tracing::log::info, Application};
+use once_cell::sync::OnceCell;
+use serde::Deserialize;
+use std::sync::Arc;
+use tonic::{transport::Certificate, Code, Status};
+use x509_parser::{extensions::GeneralName, parse_x509_certificate};
+
+static POLICY: OnceCell<Option<Policy>> = OnceCell::new();
+
+/// Loads the authorization policy if one is configured. Called at startup so that a missing or
+/// invalid policy file fails there rather than on the first request.
+pub fn load() -> Result<(), Error> {
+    POLICY.get_or_try_init(|| {
+        let config = APP.config();
+        match &config.server.authorization_policy_path {
+            Some(path) => {
+                info!("loading authorization policy from {}", path);
+                Policy::load(path).map(Some)
+            }
+            None => Ok(None),
+        }
+    })?;
+
+    Ok(())
+}
+
+/// Returns the authorization policy, or None if none is configured or it hasn't been loaded
+pub fn get() -> Option<&'static Policy> {
+    POLICY.get().and_then(|p| p.as_ref())
+}
+
+/// Authorization policy file, for example:
+///
+/// ```toml
+/// [[clients]]
+/// identity = "strategist-a.example.com"
+/// cellar_ids = ["0x1234...abcd"]
+/// functions = ["rebalance", "reinvest"]
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Policy {
+    #[serde(default)]
+    pub clients: Vec<ClientPolicy>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ClientPolicy {
+    /// Matched against the common name in the certificate's subject, the full subject, and the
+    /// certificate's DNS, email and URI subject alternative names
+    pub identity: String,
+    pub cellar_ids: Vec<String>,
+    /// Contract function names as they appear in the cellar ABI. Empty allows every function.
+    #[serde(default)]
+    pub functions: Vec<String>,
+}
+
+impl ClientPolicy {
+    fn allows_cellar(&self, cellar_id: &str) -> bool {
+        self.cellar_ids
+            .iter()
+            .any(|id| id.eq_ignore_ascii_case(cellar_id))
+    }
+
+    fn allows_function(&self, function: &str) -> bool {
+        self.functions.is_empty() || self.functions.iter().any(|f| f == function)
+    }
+}
+
+impl Policy {
+    pub fn load(path: &str) -> Result<Self, Error> {
+        let contents = std::fs::read_to_string(path)?;
+        toml::from_str(&contents).map_err(|err| {
+            ErrorKind::Config
+                .context(format!("invalid authorization policy {}: {}", path, err))
+                .into()
+        })
+    }
+
+    /// Checks that a client with any of the given identities may call the function on the cellar
+    pub fn authorize(
+        &self,
+        identities: &[String],
+        cellar_id: &str,
+        function: &str,
+    ) -> Result<(), Status> {
+        let client = identities.first().map_or("unknown", |i| i.as_str());
+        let policies: Vec<&ClientPolicy> = self
+            .clients
+            .iter()
+            .filter(|c| identities.contains(&c.identity))
+            .collect();
+        if policies.is_empty() {
+            return Err(Status::new(
+                Code::PermissionDenied,
+                format!("client {} has no authorization policy", client),
+            ));
+        }
+
+        let policies: Vec<&ClientPolicy> = policies
+            .into_iter()
+            .filter(|c| c.allows_cellar(cellar_id))
+            .collect();
+        if policies.is_empty() {
+            return Err(Status::new(
+                Code::PermissionDenied,
+                format!(
+                    "client {} is not authorized to call cellar {}",
+                    client, cellar_id
+                ),
+            ));
+        }
+
+        if !policies.iter().any(|c| c.allows_function(function)) {
+            return Err(Status::new(
+                Code::PermissionDenied,
+                format!(
+                    "client {} is not authorized to call {} on cellar {}",
+                    client, function, cellar_id
+                ),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+/// The identities of the client's leaf certificate: its subject common name, full subject, and
+/// DNS, email and URI subject alternative names
+pub fn client_identities(certs: Option<Arc<Vec<Certificate>>>) -> Vec<String> {
+    let certs = match certs {
+        Some(c) => c,
+        None => return Vec::new(),
+    };
+    let cert = match certs.first().map(|c| parse_x509_certificate(c.get_ref())) {
+        Some(Ok((_, cert))) => cert,
+        _ => return Vec::new(),
+    };
+
+    let mut identities: Vec<String> = cert
+        .subject()
+        .iter_common_name()
+        .filter_map(|cn| cn.as_str().ok())
+        .map(String::from)
+        .collect();
+    identities.push(cert.subject().to_string());
+    if let Ok(Some(san)) = cert.subject_alternative_name() {
+        for name in &san.value.general_names {
+            match name {
+                GeneralName::DNSName(n) | GeneralName::RFC822Name(n) | GeneralName::URI(n) => {
+                    identities.push(n.to_string())
+                }
+                _ => (),
+            }
+        }
+    }
+
+    identities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CELLAR_ID: &str = "0x1234567890123456789012345678901234567890";
+
+    fn policy() -> Policy {
+        toml::from_str(&format!(
+            r#"
+            [[clients]]
+            identity = "strategist-a"
+            cellar_ids = ["{}"]
+            functions = ["rebalance"]
+            "#,
+            CELLAR_ID
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn policy_allows_listed_cellar_and_function() {
+        let identities = vec!["strategist-a".to_string()];
+
+        assert!(policy()
+            .authorize(&identities, &CELLAR_ID.to_uppercase(), "rebalance")
+            .is_ok());
+    }
+
+    #[test]
+    fn policy_denies_unlisted_client_cellar_and_function() {
+        let identities = vec!["strategist-a".to_string()];
+        let other_cellar = "0x0000000000000000000000000000000000000000";
+
+        assert!(policy()
+            .authorize(&["strategist-b".to_string()], CELLAR_ID, "rebalance")
+            .is_err());
+        assert!(policy()
+            .authorize(&identities, other_cellar, "rebalance")
+            .is_err());
+        assert!(policy().authorize(&identities, CELLAR_ID, "sweep").is_err());
+    }
+}
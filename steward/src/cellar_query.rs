@@ -0,0 +1,152 @@
+//! Read-only queries of cellar state from Ethereum, so that strategists can inspect a cellar
+//! through the same Steward they submit calls to
+use crate::{
+    cellars::{self, REGISTRY},
+    error::Error,
+    utils::{get_eth_provider, sp_call_error},
+};
+use abscissa_core::tracing::log::{debug, error};
+use ethers::prelude::*;
+use std::sync::Arc;
+use steward_abi::aave_v2_stablecoin::AaveV2StablecoinCellar;
+use steward_proto::steward::{
+    self, CellarAdapterInfo, GetAdaptersRequest, GetAdaptersResponse, GetCellarStateRequest,
+    GetCellarStateResponse,
+};
+use tonic::{self, async_trait, Code, Request, Response, Status};
+
+pub struct CellarQueryHandler;
+
+#[async_trait]
+impl steward::cellar_query_server::CellarQuery for CellarQueryHandler {
+    async fn get_cellar_state(
+        &self,
+        request: Request<GetCellarStateRequest>,
+    ) -> Result<Response<GetCellarStateResponse>, Status> {
+        let cellar_id = request.get_ref().cellar_id.clone();
+        if let Err(err) = cellars::validate_cellar_id(&cellar_id) {
+            return Err(Status::new(Code::InvalidArgument, err.to_string()));
+        }
+
+        match get_cellar_state(&cellar_id).await {
+            Ok(response) => Ok(Response::new(response)),
+            Err(err) => {
+                error!("failed to query state of cellar {}: {}", cellar_id, err);
+                Err(Status::new(Code::Internal, "failed to query cellar state"))
+            }
+        }
+    }
+
+    async fn get_adapters(
+        &self,
+        _: Request<GetAdaptersRequest>,
+    ) -> Result<Response<GetAdaptersResponse>, Status> {
+        Ok(Response::new(get_adapters()))
+    }
+}
+
+/// Lists the registered cellar adapters with the signatures of the functions in their ABIs
+pub fn get_adapters() -> GetAdaptersResponse {
+    let adapters = REGISTRY
+        .adapters()
+        .map(|adapter| {
+            let mut functions: Vec<String> =
+                adapter.abi().functions().map(|f| f.signature()).collect();
+            functions.sort();
+            CellarAdapterInfo {
+                name: adapter.name().to_string(),
+                functions,
+            }
+        })
+        .collect();
+
+    GetAdaptersResponse { adapters }
+}
+
+/// Reads the cellar's view functions, all at the latest block so that the values are consistent
+/// with each other
+pub async fn get_cellar_state(cellar_id: &str) -> Result<GetCellarStateResponse, Error> {
+    let address = cellar_id
+        .parse::<H160>()
+        .map_err(|err| sp_call_error(format!("invalid ethereum address: {}", err)))?;
+    let provider = get_eth_provider().await?;
+    let block = provider.get_block_number().await?;
+    debug!("querying state of cellar {} at block {}", cellar_id, block);
+    let cellar = AaveV2StablecoinCellar::new(address, Arc::new(provider));
+
+    Ok(GetCellarStateResponse {
+        cellar_id: cellar_id.to_string(),
+        name: cellar.name().block(block).call().await?,
+        symbol: cellar.symbol().block(block).call().await?,
+        owner: format!("{:?}", cellar.owner().block(block).call().await?),
+        asset: format!("{:?}", cellar.asset().block(block).call().await?),
+        asset_decimals: cellar.asset_decimals().block(block).call().await? as u32,
+        asset_a_token: format!("{:?}", cellar.asset_a_token().block(block).call().await?),
+        total_assets: cellar.total_assets().block(block).call().await?.to_string(),
+        total_holdings: cellar
+            .total_holdings()
+            .block(block)
+            .call()
+            .await?
+            .to_string(),
+        total_locked: cellar.total_locked().block(block).call().await?.to_string(),
+        total_balance: cellar
+            .total_balance()
+            .block(block)
+            .call()
+            .await?
+            .to_string(),
+        total_supply: cellar.total_supply().block(block).call().await?.to_string(),
+        max_locked: cellar.max_locked().block(block).call().await?.to_string(),
+        deposit_limit: cellar
+            .deposit_limit()
+            .block(block)
+            .call()
+            .await?
+            .to_string(),
+        liquidity_limit: cellar
+            .liquidity_limit()
+            .block(block)
+            .call()
+            .await?
+            .to_string(),
+        is_shutdown: cellar.is_shutdown().block(block).call().await?,
+        last_accrual: cellar.last_accrual().block(block).call().await?,
+        accrual_period: cellar.accrual_period().block(block).call().await?,
+        platform_fee: cellar.platform_fee().block(block).call().await?,
+        performance_fee: cellar.performance_fee().block(block).call().await?,
+        fees_distributor: hex::encode(cellar.fees_distributor().block(block).call().await?),
+        block_number: block.as_u64(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ErrorKind;
+    use futures::executor::block_on;
+
+    #[test]
+    fn lists_registered_adapters() {
+        let response = get_adapters();
+
+        assert_eq!(response.adapters.len(), 1);
+        assert_eq!(response.adapters[0].name, "aave_v2_stablecoin");
+        assert!(response.adapters[0]
+            .functions
+            .iter()
+            .any(|f| f == "setDepositLimit(uint256)"));
+
+        let functions = &response.adapters[0].functions;
+        let mut sorted = functions.clone();
+        sorted.sort();
+        assert_eq!(*functions, sorted);
+    }
+
+    #[test]
+    fn invalid_cellar_id_is_rejected_before_querying() {
+        let err = block_on(get_cellar_state("not an address")).unwrap_err();
+
+        assert_eq!(*err.kind(), ErrorKind::SPCallError);
+    }
+}
@@ -1,11 +1,334 @@
 use abscissa_core::tracing::log::info;
-use ethers::prelude::*;
+use ethers::{
+    abi::{self, param_type::Reader, Abi, ParamType, Token},
+    prelude::*,
+};
+use lazy_static::lazy_static;
+use serde_json::{json, Map, Value};
 use std::result::Result;
+use steward_proto::steward::submit_request::CallData;
 
-use crate::error::{Error, ErrorKind};
+use crate::{
+    error::{Error, ErrorKind},
+    guardrails::Guardrails,
+};
 
 pub(crate) mod aave_v2_stablecoin;
 
+/// Name of the function cellars use to run several of their functions atomically
+const MULTICALL: &str = "multicall";
+/// Selector of the `Error(string)` revert reason emitted by `require` and `revert`
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+/// Selector of the `Panic(uint256)` error emitted by failed assertions and arithmetic errors
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+lazy_static! {
+    /// All cellar adapters supported by this version of Steward. New cellar families are added by
+    /// implementing [`CellarAdapter`] in their own module and registering them here.
+    pub static ref REGISTRY: CellarRegistry =
+        CellarRegistry::new(vec![Box::new(aave_v2_stablecoin::AaveV2StablecoinAdapter)]);
+}
+
+/// Validates and encodes contract calls for a family of cellar contracts
+pub trait CellarAdapter: Send + Sync {
+    /// Name of the cellar family, e.g. "aave_v2_stablecoin"
+    fn name(&self) -> &'static str;
+
+    /// Whether this adapter is responsible for the given call data
+    fn handles(&self, call_data: &CallData) -> bool;
+
+    /// Checks the call data before it's encoded. By default this only checks that the cellar ID is
+    /// a valid address; adapters add the checks that don't need the call to be encoded.
+    fn validate(
+        &self,
+        _call_data: &CallData,
+        cellar_id: &str,
+        _guardrails: &Guardrails,
+    ) -> Result<(), Error> {
+        validate_cellar_id(cellar_id)
+    }
+
+    /// ABI encodes the call data into a contract call on the target cellar, rejecting calls outside
+    /// the cellar's guardrails
+    fn encode(
+        &self,
+        call_data: CallData,
+        cellar_id: &str,
+        guardrails: &Guardrails,
+    ) -> Result<Vec<u8>, Error>;
+
+    /// The ABI of the cellar contract, used to decode contract calls
+    fn abi(&self) -> &'static Abi;
+
+    /// The custom errors declared in the cellar ABI, used to decode revert reasons
+    fn errors(&self) -> &'static [CustomError];
+}
+
+/// A custom error declared in a cellar ABI
+#[derive(Clone, Debug)]
+pub struct CustomError {
+    pub name: String,
+    /// Parameter names and types, in the order they appear in the error signature
+    pub inputs: Vec<(String, ParamType)>,
+    selector: [u8; 4],
+}
+
+impl CustomError {
+    /// Parses the custom errors from the `abi` field of a Hardhat contract artifact
+    pub fn from_artifact(artifact: &Value) -> Result<Vec<CustomError>, Error> {
+        let entries = match artifact["abi"].as_array() {
+            Some(e) => e,
+            None => return Err(ErrorKind::AbiError.context("artifact has no abi").into()),
+        };
+
+        entries
+            .iter()
+            .filter(|e| e["type"] == "error")
+            .map(|e| {
+                let inputs = e["inputs"]
+                    .as_array()
+                    .map(Vec::as_slice)
+                    .unwrap_or_default()
+                    .iter()
+                    .map(|input| {
+                        let kind = Reader::read(input["type"].as_str().unwrap_or_default())
+                            .map_err(|err| ErrorKind::AbiError.context(err))?;
+                        Ok((input["name"].as_str().unwrap_or_default().to_string(), kind))
+                    })
+                    .collect::<Result<Vec<(String, ParamType)>, Error>>()?;
+
+                Ok(CustomError::new(
+                    e["name"].as_str().unwrap_or_default().to_string(),
+                    inputs,
+                ))
+            })
+            .collect()
+    }
+
+    pub fn new(name: String, inputs: Vec<(String, ParamType)>) -> Self {
+        let types: Vec<ParamType> = inputs.iter().map(|(_, t)| t.clone()).collect();
+        let selector = abi::short_signature(&name, &types);
+
+        CustomError {
+            name,
+            inputs,
+            selector,
+        }
+    }
+
+    /// Describes the error with its decoded arguments, or None if they can't be decoded
+    fn describe(&self, data: &[u8]) -> Option<String> {
+        let types: Vec<ParamType> = self.inputs.iter().map(|(_, t)| t.clone()).collect();
+        let tokens = abi::decode(&types, data).ok()?;
+        let arguments: Vec<String> = self
+            .inputs
+            .iter()
+            .zip(tokens.iter())
+            .map(|((name, _), token)| format!("{}: {}", name, token_to_json(token)))
+            .collect();
+
+        Some(format!("{}({})", self.name, arguments.join(", ")))
+    }
+}
+
+/// A contract call decoded against the ABI of one of the registered cellar families
+#[derive(Clone, Debug)]
+pub struct DecodedCall {
+    /// Name of the cellar family whose ABI matched the call
+    pub cellar: &'static str,
+    pub function: String,
+    pub signature: String,
+    /// Argument names and values, in the order they appear in the function signature
+    pub arguments: Vec<(String, Token)>,
+}
+
+impl DecodedCall {
+    /// The arguments as a JSON object keyed by argument name
+    pub fn arguments_json(&self) -> Value {
+        let mut arguments = Map::new();
+        for (name, token) in &self.arguments {
+            arguments.insert(name.clone(), token_to_json(token));
+        }
+
+        Value::Object(arguments)
+    }
+
+    pub fn is_multicall(&self) -> bool {
+        self.function == MULTICALL
+    }
+
+    /// The encoded calls wrapped by a multicall, in the order they're executed. Empty for other
+    /// functions.
+    pub fn wrapped_calls(&self) -> Result<Vec<&[u8]>, Error> {
+        if !self.is_multicall() {
+            return Ok(Vec::new());
+        }
+
+        let invalid = || -> Error {
+            ErrorKind::AbiError
+                .context("multicall arguments are not an array of calls")
+                .into()
+        };
+        match self.arguments.first() {
+            Some((_, Token::Array(calls))) => calls
+                .iter()
+                .map(|call| match call {
+                    Token::Bytes(call) => Ok(call.as_slice()),
+                    _ => Err(invalid()),
+                })
+                .collect(),
+            _ => Err(invalid()),
+        }
+    }
+}
+
+/// Dispatches call data to the [`CellarAdapter`] that handles it
+pub struct CellarRegistry {
+    adapters: Vec<Box<dyn CellarAdapter>>,
+}
+
+impl CellarRegistry {
+    pub fn new(adapters: Vec<Box<dyn CellarAdapter>>) -> Self {
+        Self { adapters }
+    }
+
+    /// Names of all registered adapters
+    pub fn names(&self) -> Vec<&'static str> {
+        self.adapters.iter().map(|a| a.name()).collect()
+    }
+
+    /// All registered adapters, in the order calls are dispatched to them
+    pub fn adapters(&self) -> impl Iterator<Item = &dyn CellarAdapter> {
+        self.adapters.iter().map(|a| a.as_ref())
+    }
+
+    pub fn get(&self, call_data: &CallData) -> Result<&dyn CellarAdapter, Error> {
+        match self.adapters.iter().find(|a| a.handles(call_data)) {
+            Some(adapter) => Ok(adapter.as_ref()),
+            None => Err(ErrorKind::SPCallError
+                .context("no cellar adapter registered for call data")
+                .into()),
+        }
+    }
+
+    pub fn validate(
+        &self,
+        call_data: &CallData,
+        cellar_id: &str,
+        guardrails: &Guardrails,
+    ) -> Result<(), Error> {
+        self.get(call_data)?
+            .validate(call_data, cellar_id, guardrails)
+    }
+
+    /// Validates the call data, then encodes it
+    pub fn encode(
+        &self,
+        call_data: CallData,
+        cellar_id: &str,
+        guardrails: &Guardrails,
+    ) -> Result<Vec<u8>, Error> {
+        let adapter = self.get(&call_data)?;
+        adapter.validate(&call_data, cellar_id, guardrails)?;
+        adapter.encode(call_data, cellar_id, guardrails)
+    }
+
+    /// Decodes a contract call by matching its function selector against each adapter's ABI
+    pub fn decode(&self, encoded_call: &[u8]) -> Result<DecodedCall, Error> {
+        if encoded_call.len() < 4 {
+            return Err(ErrorKind::AbiError
+                .context("contract call is shorter than a function selector")
+                .into());
+        }
+        let (selector, data) = encoded_call.split_at(4);
+
+        for adapter in &self.adapters {
+            let function = match adapter
+                .abi()
+                .functions()
+                .find(|f| f.short_signature() == selector)
+            {
+                Some(f) => f,
+                None => continue,
+            };
+            let tokens = function
+                .decode_input(data)
+                .map_err(|err| ErrorKind::AbiError.context(err))?;
+
+            return Ok(DecodedCall {
+                cellar: adapter.name(),
+                function: function.name.clone(),
+                signature: function.signature(),
+                arguments: function
+                    .inputs
+                    .iter()
+                    .map(|p| p.name.clone())
+                    .zip(tokens)
+                    .collect(),
+            });
+        }
+
+        Err(ErrorKind::AbiError
+            .context(format!(
+                "function selector 0x{} not found in any cellar ABI",
+                hex::encode(selector)
+            ))
+            .into())
+    }
+
+    /// The names of the functions a contract call would run. A multicall runs the functions it
+    /// wraps rather than a function of its own.
+    pub fn functions(&self, encoded_call: &[u8]) -> Result<Vec<String>, Error> {
+        let decoded = self.decode(encoded_call)?;
+        if !decoded.is_multicall() {
+            return Ok(vec![decoded.function]);
+        }
+
+        let mut functions = Vec::new();
+        for call in decoded.wrapped_calls()? {
+            functions.extend(self.functions(call)?);
+        }
+
+        Ok(functions)
+    }
+
+    /// Describes why a contract call reverted from its revert data: the reason string of an
+    /// `Error(string)`, the code of a `Panic(uint256)`, or a custom error from one of the adapters'
+    /// ABIs with its arguments
+    pub fn decode_revert(&self, data: &[u8]) -> String {
+        if data.len() < 4 {
+            return "reverted without a reason".to_string();
+        }
+        let (selector, args) = data.split_at(4);
+
+        if selector == ERROR_SELECTOR {
+            if let Ok(Some(Token::String(reason))) =
+                abi::decode(&[ParamType::String], args).map(|t| t.into_iter().next())
+            {
+                return reason;
+            }
+        }
+        if selector == PANIC_SELECTOR {
+            if let Ok(Some(Token::Uint(code))) =
+                abi::decode(&[ParamType::Uint(256)], args).map(|t| t.into_iter().next())
+            {
+                return format!("panic with code {:#x}", code);
+            }
+        }
+        if let Some(description) = self
+            .adapters
+            .iter()
+            .flat_map(|a| a.errors())
+            .find(|e| e.selector == selector)
+            .and_then(|e| e.describe(args))
+        {
+            return description;
+        }
+
+        format!("unrecognized revert data 0x{}", hex::encode(data))
+    }
+}
+
 pub fn validate_cellar_id(cellar_id: &str) -> Result<(), Error> {
     if let Err(err) = cellar_id.parse::<H160>() {
         return Err(ErrorKind::SPCallError
@@ -16,6 +339,21 @@ pub fn validate_cellar_id(cellar_id: &str) -> Result<(), Error> {
     Ok(())
 }
 
+/// Numbers are rendered as decimal strings since they may not fit in a JSON number
+fn token_to_json(token: &Token) -> Value {
+    match token {
+        Token::Address(a) => json!(format!("{:?}", a)),
+        Token::FixedBytes(b) | Token::Bytes(b) => json!(format!("0x{}", hex::encode(b))),
+        Token::Int(i) => json!(I256::from_raw(*i).to_string()),
+        Token::Uint(u) => json!(u.to_string()),
+        Token::Bool(b) => json!(b),
+        Token::String(s) => json!(s),
+        Token::FixedArray(tokens) | Token::Array(tokens) | Token::Tuple(tokens) => {
+            Value::Array(tokens.iter().map(token_to_json).collect())
+        }
+    }
+}
+
 pub fn log_cellar_call(cellar_name: &str, function_name: &str, cellar_id: &str) {
     info!(
         "encoding {}.{} call for cellar {}",
@@ -26,6 +364,12 @@ pub fn log_cellar_call(cellar_name: &str, function_name: &str, cellar_id: &str)
 #[cfg(test)]
 mod tests {
     use super::*;
+    use steward_proto::steward::{
+        aave_v2_stablecoin::{
+            Accrue, Function, Multicall, SetDepositLimit, SetFeesDistributor, TransferOwnership,
+        },
+        AaveV2Stablecoin,
+    };
 
     #[test]
     fn invalid_cellar_id_format_errors() {
@@ -42,4 +386,153 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn registry_dispatches_to_adapter() {
+        let call_data = CallData::AaveV2Stablecoin(AaveV2Stablecoin {
+            function: Some(Function::Accrue(Accrue {})),
+        });
+        let cellar_id = "0x0000000000000000000000000000000000000000";
+
+        assert_eq!(
+            REGISTRY.get(&call_data).unwrap().name(),
+            "aave_v2_stablecoin"
+        );
+        assert!(REGISTRY
+            .encode(call_data, cellar_id, &Guardrails::default())
+            .is_ok());
+    }
+
+    #[test]
+    fn registry_decodes_encoded_call() {
+        let call_data = CallData::AaveV2Stablecoin(AaveV2Stablecoin {
+            function: Some(Function::SetDepositLimit(SetDepositLimit {
+                limit: "1000".to_string(),
+            })),
+        });
+        let cellar_id = "0x0000000000000000000000000000000000000000";
+        let encoded_call = REGISTRY
+            .encode(call_data, cellar_id, &Guardrails::default())
+            .unwrap();
+        let decoded = REGISTRY.decode(&encoded_call).unwrap();
+
+        assert_eq!(decoded.cellar, "aave_v2_stablecoin");
+        assert_eq!(decoded.function, "setDepositLimit");
+        assert_eq!(decoded.arguments_json(), json!({ "newLimit": "1000" }));
+    }
+
+    #[test]
+    fn registry_validates_owner_function_arguments() {
+        let cellar_id = "0x0000000000000000000000000000000000000000";
+        let encode = |function| {
+            REGISTRY.encode(
+                CallData::AaveV2Stablecoin(AaveV2Stablecoin {
+                    function: Some(function),
+                }),
+                cellar_id,
+                &Guardrails::default(),
+            )
+        };
+
+        assert!(encode(Function::TransferOwnership(TransferOwnership {
+            new_owner: cellar_id.to_string(),
+        }))
+        .is_err());
+        assert!(encode(Function::SetFeesDistributor(SetFeesDistributor {
+            new_fees_distributor: "0x".to_string() + &"11".repeat(31),
+        }))
+        .is_err());
+        assert!(encode(Function::SetFeesDistributor(SetFeesDistributor {
+            new_fees_distributor: "0x".to_string() + &"11".repeat(32),
+        }))
+        .is_ok());
+    }
+
+    #[test]
+    fn registry_lists_multicall_functions() {
+        let cellar_id = "0x0000000000000000000000000000000000000000";
+        let call = |function| AaveV2Stablecoin {
+            function: Some(function),
+        };
+        let multicall =
+            |calls| CallData::AaveV2Stablecoin(call(Function::Multicall(Multicall { calls })));
+        let encoded_call = REGISTRY
+            .encode(
+                multicall(vec![
+                    call(Function::Accrue(Accrue {})),
+                    call(Function::SetDepositLimit(SetDepositLimit {
+                        limit: "1000".to_string(),
+                    })),
+                ]),
+                cellar_id,
+                &Guardrails::default(),
+            )
+            .unwrap();
+
+        assert_eq!(
+            REGISTRY.decode(&encoded_call).unwrap().function,
+            "multicall"
+        );
+        assert_eq!(
+            REGISTRY.functions(&encoded_call).unwrap(),
+            vec!["accrue", "setDepositLimit"]
+        );
+        assert!(REGISTRY
+            .encode(
+                multicall(vec![call(Function::Multicall(Multicall { calls: vec![] }))]),
+                cellar_id,
+                &Guardrails::default(),
+            )
+            .is_err());
+    }
+
+    #[test]
+    fn registry_rejects_unknown_selector() {
+        assert!(REGISTRY.decode(&[0xde, 0xad, 0xbe, 0xef]).is_err());
+        assert!(REGISTRY.decode(&[0xde, 0xad]).is_err());
+    }
+
+    #[test]
+    fn registry_decodes_revert_reasons() {
+        let reason = abi::encode(&[Token::String("not enough assets".to_string())]);
+        let deposit_restricted = CustomError::new(
+            "USR_DepositRestricted".to_string(),
+            vec![
+                ("assets".to_string(), ParamType::Uint(256)),
+                ("maxDeposit".to_string(), ParamType::Uint(256)),
+            ],
+        );
+        let args = abi::encode(&[Token::Uint(1000.into()), Token::Uint(500.into())]);
+
+        assert_eq!(
+            REGISTRY.decode_revert(&[&ERROR_SELECTOR[..], &reason].concat()),
+            "not enough assets"
+        );
+        assert_eq!(
+            REGISTRY.decode_revert(&[&deposit_restricted.selector[..], &args].concat()),
+            r#"USR_DepositRestricted(assets: "1000", maxDeposit: "500")"#
+        );
+        assert_eq!(REGISTRY.decode_revert(&[]), "reverted without a reason");
+    }
+
+    #[test]
+    fn registry_rejects_empty_function() {
+        let call_data = CallData::AaveV2Stablecoin(AaveV2Stablecoin { function: None });
+        let cellar_id = "0x0000000000000000000000000000000000000000";
+
+        assert!(REGISTRY
+            .validate(&call_data, cellar_id, &Guardrails::default())
+            .is_err());
+    }
+
+    #[test]
+    fn registry_validates_before_encoding() {
+        let call_data = CallData::AaveV2Stablecoin(AaveV2Stablecoin {
+            function: Some(Function::Accrue(Accrue {})),
+        });
+
+        assert!(REGISTRY
+            .encode(call_data, "thisaintright", &Guardrails::default())
+            .is_err());
+    }
 }
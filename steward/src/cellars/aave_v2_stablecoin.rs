@@ -1,22 +1,93 @@
 use crate::{
-    error::Error,
+    error::{Error, ErrorKind},
+    guardrails::Guardrails,
     utils::{sp_call_error, string_to_u256},
 };
 use ethers::{
-    abi::AbiEncode,
+    abi::{Abi, AbiEncode},
     contract::EthCall,
-    prelude::{H160, U256},
+    prelude::{Bytes, H160, U256},
 };
+use lazy_static::lazy_static;
 use std::convert::TryInto;
 use steward_abi::aave_v2_stablecoin::*;
-use steward_proto::steward::aave_v2_stablecoin::Function::{self, *};
+use steward_proto::steward::{
+    aave_v2_stablecoin::Function::{self, *},
+    submit_request::CallData,
+};
 
-use super::log_cellar_call;
+use super::{log_cellar_call, validate_cellar_id, CellarAdapter, CustomError};
 
 const CELLAR_NAME: &str = "aave_v2_stablecoin";
 const LOG_PREFIX: &str = "AaveV2StablcoinCellar";
 
-pub fn get_encoded_call(function: Function, cellar_id: String) -> Result<Vec<u8>, Error> {
+lazy_static! {
+    /// The Hardhat artifact the `steward_abi` bindings are generated from
+    static ref ARTIFACT: serde_json::Value =
+        serde_json::from_str(include_str!("../../../steward_abi/AaveV2StablecoinCellar.json"))
+            .expect("invalid cellar artifact");
+    static ref ABI: Abi =
+        serde_json::from_value(ARTIFACT["abi"].clone()).expect("invalid cellar abi");
+    static ref ERRORS: Vec<CustomError> =
+        CustomError::from_artifact(&ARTIFACT).expect("invalid cellar abi errors");
+}
+
+pub struct AaveV2StablecoinAdapter;
+
+impl CellarAdapter for AaveV2StablecoinAdapter {
+    fn name(&self) -> &'static str {
+        CELLAR_NAME
+    }
+
+    fn handles(&self, call_data: &CallData) -> bool {
+        matches!(call_data, CallData::AaveV2Stablecoin(_))
+    }
+
+    fn validate(
+        &self,
+        call_data: &CallData,
+        cellar_id: &str,
+        _guardrails: &Guardrails,
+    ) -> Result<(), Error> {
+        validate_cellar_id(cellar_id)?;
+        match call_data {
+            CallData::AaveV2Stablecoin(call) if call.function.is_none() => {
+                Err(ErrorKind::Http.context("empty function data").into())
+            }
+            CallData::AaveV2Stablecoin(_) => Ok(()),
+        }
+    }
+
+    fn encode(
+        &self,
+        call_data: CallData,
+        cellar_id: &str,
+        guardrails: &Guardrails,
+    ) -> Result<Vec<u8>, Error> {
+        match call_data {
+            CallData::AaveV2Stablecoin(call) => match call.function {
+                Some(function) => get_encoded_call(function, cellar_id.to_string(), guardrails),
+                None => Err(ErrorKind::Http.context("empty function data").into()),
+            },
+        }
+    }
+
+    fn abi(&self) -> &'static Abi {
+        &ABI
+    }
+
+    fn errors(&self) -> &'static [CustomError] {
+        &ERRORS
+    }
+}
+
+pub fn get_encoded_call(
+    function: Function,
+    cellar_id: String,
+    guardrails: &Guardrails,
+) -> Result<Vec<u8>, Error> {
+    guardrails.check_enabled(&function_name(&function))?;
+
     match function {
         Accrue(_) => {
             log_cellar_call(
@@ -101,13 +172,15 @@ pub fn get_encoded_call(function: Function, cellar_id: String) -> Result<Vec<u8>
 
             validate_route(results.clone())?;
 
-            let route = results
-                .iter()
-                .map(|r| r.unwrap())
-                .collect::<Vec<H160>>()
+            let route = results.iter().map(|r| r.unwrap()).collect::<Vec<H160>>();
+            guardrails.check_route(&route)?;
+            let route = route
                 .try_into()
                 .expect("failed to convert 'route' addresses to array");
 
+            let swap_types: Vec<u64> = params.swap_params.iter().map(|sp| sp.swap_type).collect();
+            guardrails.check_swap_types(&swap_types)?;
+
             let swap_params = params
                 .swap_params
                 .iter()
@@ -121,6 +194,7 @@ pub fn get_encoded_call(function: Function, cellar_id: String) -> Result<Vec<u8>
                 .expect("failed to convert 'swap_params' vec to array");
 
             let min_assets_out = string_to_u256(params.min_assets_out)?;
+            guardrails.check_min_assets_out(min_assets_out)?;
 
             log_cellar_call(
                 CELLAR_NAME,
@@ -136,6 +210,7 @@ pub fn get_encoded_call(function: Function, cellar_id: String) -> Result<Vec<u8>
         }
         Reinvest(params) => {
             let min_assets_out = string_to_u256(params.min_assets_out)?;
+            guardrails.check_min_assets_out(min_assets_out)?;
             log_cellar_call(
                 CELLAR_NAME,
                 &ReinvestCall::function_name(),
@@ -156,6 +231,7 @@ pub fn get_encoded_call(function: Function, cellar_id: String) -> Result<Vec<u8>
         }
         SetDepositLimit(params) => {
             let new_limit = string_to_u256(params.limit)?;
+            guardrails.check_deposit_limit(new_limit)?;
             log_cellar_call(
                 CELLAR_NAME,
                 &SetDepositLimitCall::function_name(),
@@ -166,6 +242,7 @@ pub fn get_encoded_call(function: Function, cellar_id: String) -> Result<Vec<u8>
         }
         SetLiquidityLimit(params) => {
             let new_limit = string_to_u256(params.limit)?;
+            guardrails.check_liquidity_limit(new_limit)?;
             log_cellar_call(
                 CELLAR_NAME,
                 &SetLiquidityLimitCall::function_name(),
@@ -183,7 +260,141 @@ pub fn get_encoded_call(function: Function, cellar_id: String) -> Result<Vec<u8>
             let call = SendFeesCall {};
             Ok(AaveV2StablecoinCellarCalls::SendFees(call).encode())
         }
+        Sweep(params) => {
+            let token = parse_address("Sweep 'token'", &params.token)?;
+            let to = parse_nonzero_address("Sweep 'to'", &params.to)?;
+            log_cellar_call(CELLAR_NAME, &SweepCall::function_name(), cellar_id.as_str());
+            let call = SweepCall { token, to };
+            Ok(AaveV2StablecoinCellarCalls::Sweep(call).encode())
+        }
+        SetTrust(params) => {
+            let position = parse_address("SetTrust 'position'", &params.position)?;
+            log_cellar_call(
+                CELLAR_NAME,
+                &SetTrustCall::function_name(),
+                cellar_id.as_str(),
+            );
+            let call = SetTrustCall {
+                position,
+                trust: params.trust,
+            };
+            Ok(AaveV2StablecoinCellarCalls::SetTrust(call).encode())
+        }
+        SetFeesDistributor(params) => {
+            let new_fees_distributor = parse_bytes32(
+                "SetFeesDistributor 'new_fees_distributor'",
+                &params.new_fees_distributor,
+            )?;
+            log_cellar_call(
+                CELLAR_NAME,
+                &SetFeesDistributorCall::function_name(),
+                cellar_id.as_str(),
+            );
+            let call = SetFeesDistributorCall {
+                new_fees_distributor,
+            };
+            Ok(AaveV2StablecoinCellarCalls::SetFeesDistributor(call).encode())
+        }
+        InitiateShutdown(params) => {
+            log_cellar_call(
+                CELLAR_NAME,
+                &InitiateShutdownCall::function_name(),
+                cellar_id.as_str(),
+            );
+            let call = InitiateShutdownCall {
+                empty_position: params.empty_position,
+            };
+            Ok(AaveV2StablecoinCellarCalls::InitiateShutdown(call).encode())
+        }
+        LiftShutdown(_) => {
+            log_cellar_call(
+                CELLAR_NAME,
+                &LiftShutdownCall::function_name(),
+                cellar_id.as_str(),
+            );
+            let call = LiftShutdownCall {};
+            Ok(AaveV2StablecoinCellarCalls::LiftShutdown(call).encode())
+        }
+        TransferOwnership(params) => {
+            let new_owner =
+                parse_nonzero_address("TransferOwnership 'new_owner'", &params.new_owner)?;
+            log_cellar_call(
+                CELLAR_NAME,
+                &TransferOwnershipCall::function_name(),
+                cellar_id.as_str(),
+            );
+            let call = TransferOwnershipCall { new_owner };
+            Ok(AaveV2StablecoinCellarCalls::TransferOwnership(call).encode())
+        }
+        Multicall(params) => {
+            if params.calls.is_empty() {
+                return Err(sp_call_error(format!(
+                    "{}: Multicall 'calls': array must contain at least one call",
+                    LOG_PREFIX
+                )));
+            }
+
+            // Each call is encoded and checked against the guardrails as if it were sent alone
+            let data = params
+                .calls
+                .into_iter()
+                .enumerate()
+                .map(|(i, call)| {
+                    let function = match call.function {
+                        Some(Multicall(_)) => {
+                            return Err(sp_call_error(format!(
+                                "{}: Multicall 'calls': call {} cannot be a multicall",
+                                LOG_PREFIX, i
+                            )))
+                        }
+                        Some(f) => f,
+                        None => {
+                            return Err(sp_call_error(format!(
+                                "{}: Multicall 'calls': call {} is empty",
+                                LOG_PREFIX, i
+                            )))
+                        }
+                    };
+
+                    get_encoded_call(function, cellar_id.clone(), guardrails).map(Bytes::from)
+                })
+                .collect::<Result<Vec<Bytes>, Error>>()?;
+
+            log_cellar_call(
+                CELLAR_NAME,
+                &MulticallCall::function_name(),
+                cellar_id.as_str(),
+            );
+            let call = MulticallCall { data };
+            Ok(AaveV2StablecoinCellarCalls::Multicall(call).encode())
+        }
+    }
+}
+
+/// The name of the contract function as it appears in the cellar ABI
+fn function_name(function: &Function) -> String {
+    match function {
+        Accrue(_) => AccrueCall::function_name(),
+        ClaimAndUnstake(_) => ClaimAndUnstakeCall::function_name(),
+        EnterPosition(_) => EnterPositionCall::function_name(),
+        EnterPositionWithAssets(_) => EnterPositionWithAssetsCall::function_name(),
+        ExitPosition(_) => ExitPositionCall::function_name(),
+        ExitPositionWithAssets(_) => ExitPositionWithAssetsCall::function_name(),
+        Rebalance(_) => RebalanceCall::function_name(),
+        Reinvest(_) => ReinvestCall::function_name(),
+        SetAccrualPeriod(_) => SetAccrualPeriodCall::function_name(),
+        SetDepositLimit(_) => SetDepositLimitCall::function_name(),
+        SetLiquidityLimit(_) => SetLiquidityLimitCall::function_name(),
+        SendFees(_) => SendFeesCall::function_name(),
+        Sweep(_) => SweepCall::function_name(),
+        SetTrust(_) => SetTrustCall::function_name(),
+        SetFeesDistributor(_) => SetFeesDistributorCall::function_name(),
+        InitiateShutdown(_) => InitiateShutdownCall::function_name(),
+        LiftShutdown(_) => LiftShutdownCall::function_name(),
+        TransferOwnership(_) => TransferOwnershipCall::function_name(),
+        Multicall(_) => MulticallCall::function_name(),
     }
+    .to_string()
 }
 
 fn validate_route(results: Vec<Result<H160, &String>>) -> Result<(), Error> {
@@ -202,3 +413,38 @@ fn validate_route(results: Vec<Result<H160, &String>>) -> Result<(), Error> {
 
     Ok(())
 }
+
+fn parse_address(name: &str, address: &str) -> Result<H160, Error> {
+    address.parse::<H160>().map_err(|_| {
+        sp_call_error(format!(
+            "{}: {}: invalid address {}",
+            LOG_PREFIX, name, address
+        ))
+    })
+}
+
+/// For recipients of assets or control of the cellar, where the zero address is almost certainly
+/// a client bug and would lose them for good
+fn parse_nonzero_address(name: &str, address: &str) -> Result<H160, Error> {
+    let address = parse_address(name, address)?;
+    if address.is_zero() {
+        return Err(sp_call_error(format!(
+            "{}: {}: cannot be the zero address",
+            LOG_PREFIX, name
+        )));
+    }
+
+    Ok(address)
+}
+
+fn parse_bytes32(name: &str, value: &str) -> Result<[u8; 32], Error> {
+    let bytes = hex::decode(value.trim_start_matches("0x"))
+        .map_err(|err| sp_call_error(format!("{}: {}: invalid hex: {}", LOG_PREFIX, name, err)))?;
+
+    bytes.try_into().map_err(|_| {
+        sp_call_error(format!(
+            "{}: {}: must be exactly 32 bytes",
+            LOG_PREFIX, name
+        ))
+    })
+}
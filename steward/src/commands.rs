@@ -10,20 +10,29 @@
 //! See the `impl Configurable` below for how to specify the path to the
 //! application's configuration file.
 
+mod cellar;
+mod client;
 mod config_cmd;
+mod cork;
 mod cosmos_to_eth;
 mod deploy;
 mod eth_to_cosmos;
+mod history;
 mod keys;
 mod orchestrator;
 mod schedule_corks;
 mod sign_delegate_keys;
 mod start;
 
-use self::{config_cmd::ConfigCmd, keys::KeysCmd, schedule_corks::ScheduleCmd, start::StartCmd};
+use self::{
+    config_cmd::ConfigCmd, cork::CorkCmd, keys::KeysCmd, schedule_corks::ScheduleCmd,
+    start::StartCmd,
+};
 
 use crate::config::StewardConfig;
-use abscissa_core::{clap::Parser, Command, Configurable, FrameworkError, Runnable};
+use abscissa_core::{
+    clap::Parser, config::Override, Command, Configurable, FrameworkError, Runnable,
+};
 use std::{env, path::PathBuf};
 
 /// Steward Configuration Filename
@@ -32,6 +41,10 @@ pub const CONFIG_FILE: &str = "steward.toml";
 /// Steward Subcommands
 #[derive(Command, Debug, Parser, Runnable)]
 pub enum StewardCmd {
+    #[clap(subcommand)]
+    Cellar(cellar::CellarCmd),
+    #[clap(subcommand)]
+    Cork(CorkCmd),
     #[clap(subcommand)]
     Schedule(ScheduleCmd),
     #[clap(subcommand)]
@@ -42,6 +55,7 @@ pub enum StewardCmd {
     #[clap(subcommand)]
     Deploy(deploy::DeployCmd),
     EthToCosmos(eth_to_cosmos::EthToCosmosCmd),
+    History(history::HistoryCmd),
     #[clap(subcommand)]
     Orchestrator(orchestrator::OrchestratorCmd),
     SignDelegateKeys(sign_delegate_keys::SignDelegateKeysCmd),
@@ -97,6 +111,9 @@ impl Configurable<StewardConfig> for EntryPoint {
     /// This can be safely deleted if you don't want to override config
     /// settings from command-line options.
     fn process_config(&self, config: StewardConfig) -> Result<StewardConfig, FrameworkError> {
-        Ok(config)
+        match &self.cmd {
+            StewardCmd::Start(cmd) => cmd.override_config(config),
+            _ => Ok(config),
+        }
     }
 }
@@ -0,0 +1,9 @@
+mod inspect;
+
+use abscissa_core::{clap::Parser, Command, Runnable};
+
+/// Cellar commands
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum CellarCmd {
+    Inspect(inspect::InspectCmd),
+}
@@ -0,0 +1,52 @@
+use crate::{application::APP, cellar_query, prelude::*};
+use abscissa_core::{clap::Parser, Command, Runnable};
+
+/// Cellar inspect subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "\nReads the view functions of a cellar from the configured Ethereum RPC and prints a snapshot of its state. Every value is read at the same block."
+)]
+pub struct InspectCmd {
+    /// Address of the cellar.
+    cellar_id: String,
+}
+
+impl Runnable for InspectCmd {
+    fn run(&self) {
+        abscissa_tokio::run_with_actix(&APP, async {
+            let state = cellar_query::get_cellar_state(&self.cellar_id)
+                .await
+                .unwrap_or_else(|err| {
+                    status_err!("failed to query cellar state: {}", err);
+                    std::process::exit(1);
+                });
+
+            println!("cellar ID: {}", state.cellar_id);
+            println!("block number: {}", state.block_number);
+            println!("name: {}", state.name);
+            println!("symbol: {}", state.symbol);
+            println!("owner: {}", state.owner);
+            println!("asset: {}", state.asset);
+            println!("asset decimals: {}", state.asset_decimals);
+            println!("asset aToken: {}", state.asset_a_token);
+            println!("total assets: {}", state.total_assets);
+            println!("total holdings: {}", state.total_holdings);
+            println!("total locked: {}", state.total_locked);
+            println!("total balance: {}", state.total_balance);
+            println!("total supply: {}", state.total_supply);
+            println!("max locked: {}", state.max_locked);
+            println!("deposit limit: {}", state.deposit_limit);
+            println!("liquidity limit: {}", state.liquidity_limit);
+            println!("shutdown: {}", state.is_shutdown);
+            println!("last accrual: {}", state.last_accrual);
+            println!("accrual period: {}s", state.accrual_period);
+            println!("platform fee: {}", state.platform_fee);
+            println!("performance fee: {}", state.performance_fee);
+            println!("fees distributor: {}", state.fees_distributor);
+        })
+        .unwrap_or_else(|e| {
+            status_err!("executor exited with error: {}", e);
+            std::process::exit(1);
+        });
+    }
+}
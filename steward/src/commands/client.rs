@@ -0,0 +1,69 @@
+//! Connection options shared by the subcommands that call a running Steward server
+
+use crate::application::APP;
+use abscissa_core::{clap::Parser, Application};
+use steward_proto::steward::contract_call_client::ContractCallClient;
+use tonic::transport::{Certificate, Channel, ClientTlsConfig, Identity};
+
+#[derive(Debug, Parser)]
+pub struct ClientArgs {
+    /// URL of the Steward server. Defaults to the configured port on localhost.
+    #[clap(long)]
+    url: Option<String>,
+
+    /// CA certificate used to verify the server. Defaults to the configured server certificate.
+    #[clap(long)]
+    ca_cert: Option<String>,
+
+    /// Client certificate presented to the server.
+    #[clap(long)]
+    client_cert: String,
+
+    /// Key of the client certificate.
+    #[clap(long)]
+    client_key: String,
+
+    /// Domain name expected in the server certificate, if it differs from the URL's host.
+    #[clap(long)]
+    domain: Option<String>,
+}
+
+impl ClientArgs {
+    pub async fn connect(&self) -> Result<ContractCallClient<Channel>, String> {
+        let config = APP.config();
+        let url = match &self.url {
+            Some(url) => url.clone(),
+            None => format!("https://localhost:{}", config.server.port),
+        };
+        let ca_cert_path = self
+            .ca_cert
+            .clone()
+            .unwrap_or_else(|| config.server.server_cert_path.clone());
+        let ca_cert = read(&ca_cert_path).await?;
+        let client_cert = read(&self.client_cert).await?;
+        let client_key = read(&self.client_key).await?;
+
+        let mut tls_config = ClientTlsConfig::new()
+            .ca_certificate(Certificate::from_pem(ca_cert))
+            .identity(Identity::from_pem(client_cert, client_key));
+        if let Some(domain) = &self.domain {
+            tls_config = tls_config.domain_name(domain.clone());
+        }
+
+        let channel = Channel::from_shared(url.clone())
+            .map_err(|err| format!("invalid url {}: {}", url, err))?
+            .tls_config(tls_config)
+            .map_err(|err| format!("invalid tls config: {}", err))?
+            .connect()
+            .await
+            .map_err(|err| format!("failed to connect to {}: {}", url, err))?;
+
+        Ok(ContractCallClient::new(channel))
+    }
+}
+
+async fn read(path: &str) -> Result<Vec<u8>, String> {
+    tokio::fs::read(path)
+        .await
+        .map_err(|err| format!("failed to read {}: {}", path, err))
+}
@@ -0,0 +1,13 @@
+mod decode;
+mod preview;
+mod status;
+
+use abscissa_core::{clap::Parser, Command, Runnable};
+
+/// Cork commands
+#[derive(Command, Debug, Parser, Runnable)]
+pub enum CorkCmd {
+    Decode(decode::DecodeCmd),
+    Preview(preview::PreviewCmd),
+    Status(status::StatusCmd),
+}
@@ -0,0 +1,48 @@
+use crate::{cork, prelude::*};
+use abscissa_core::{clap::Parser, Command, Runnable};
+use serde_json::{json, Value};
+use steward_proto::steward::DecodedCall;
+
+/// Cork decode subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "\nDecodes the hex encoded contract call of a cork into the cellar function it calls and its arguments, printed as JSON. The calls wrapped by a multicall are decoded too."
+)]
+pub struct DecodeCmd {
+    /// Hex encoded contract call, with or without a 0x prefix.
+    encoded_call: String,
+}
+
+impl Runnable for DecodeCmd {
+    fn run(&self) {
+        let encoded_call =
+            hex::decode(self.encoded_call.trim_start_matches("0x")).unwrap_or_else(|err| {
+                status_err!("invalid encoded call: {}", err);
+                std::process::exit(1);
+            });
+        let call = cork::decode::decode(&encoded_call).unwrap_or_else(|err| {
+            status_err!("failed to decode contract call: {}", err);
+            std::process::exit(1);
+        });
+
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&call_json(&call)).expect("failed to serialize call")
+        );
+    }
+}
+
+fn call_json(call: &DecodedCall) -> Value {
+    let mut value = json!({
+        "cellar": call.cellar,
+        "function": call.function,
+        "signature": call.signature,
+        "selector": format!("0x{}", call.selector),
+        "arguments": serde_json::from_str::<Value>(&call.arguments).unwrap_or(Value::Null),
+    });
+    if !call.calls.is_empty() {
+        value["calls"] = Value::Array(call.calls.iter().map(call_json).collect());
+    }
+
+    value
+}
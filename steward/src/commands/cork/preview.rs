@@ -0,0 +1,319 @@
+use crate::{application::APP, commands::client::ClientArgs, prelude::*};
+use abscissa_core::{clap::Parser, Command, Runnable};
+use steward_proto::steward::{
+    aave_v2_stablecoin::{self, rebalance::SwapParams, Function},
+    submit_request::CallData,
+    AaveV2Stablecoin, PreviewRequest, SubmitRequest,
+};
+
+/// Cork preview subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "\nValidates and encodes a cellar function call on a running Steward server exactly as a submission would, and prints the cork that would be sent without sending it. The server requires a client certificate signed by its client CA."
+)]
+pub struct PreviewCmd {
+    /// Target cellar of the function call.
+    #[clap(short = 'i', long)]
+    cellar_id: String,
+
+    #[clap(flatten)]
+    client: ClientArgs,
+
+    #[clap(subcommand)]
+    call: CellarCall,
+}
+
+#[derive(Debug, Parser)]
+enum CellarCall {
+    /// Aave V2 stablecoin cellar functions
+    #[clap(name = "aave2-clr-s", subcommand)]
+    AaveV2StablecoinCellar(AaveV2StablecoinFunction),
+}
+
+#[derive(Debug, Parser)]
+enum AaveV2StablecoinFunction {
+    Accrue,
+    ClaimAndUnstake,
+    EnterPosition,
+    EnterPositionWithAssets {
+        /// Amount of assets to enter the position with.
+        #[clap(long)]
+        assets: String,
+    },
+    ExitPosition,
+    ExitPositionWithAssets {
+        /// Amount of assets to exit the position with.
+        #[clap(long)]
+        assets: String,
+    },
+    Rebalance {
+        /// Address in the Curve swap route, in order. Repeat for each of the 9 addresses, padding
+        /// with the zero address.
+        #[clap(long)]
+        route: Vec<String>,
+
+        /// Swap as in_index:out_index:swap_type. Repeat for each of the 4 swaps.
+        #[clap(long = "swap")]
+        swaps: Vec<String>,
+
+        /// Minimum acceptable assets to be received from the swap.
+        #[clap(long)]
+        min_assets_out: String,
+    },
+    Reinvest {
+        /// Minimum acceptable assets to be received from the swap.
+        #[clap(long)]
+        min_assets_out: String,
+    },
+    SetAccrualPeriod {
+        /// Accrual period in seconds.
+        #[clap(long)]
+        new_accrual_period: u32,
+    },
+    SetDepositLimit {
+        /// New deposit limit.
+        #[clap(long)]
+        limit: String,
+    },
+    SetLiquidityLimit {
+        /// New liquidity limit.
+        #[clap(long)]
+        limit: String,
+    },
+    SendFees,
+    Sweep {
+        /// Address of the token to sweep.
+        #[clap(long)]
+        token: String,
+
+        /// Address to send the swept tokens to.
+        #[clap(long)]
+        to: String,
+    },
+    SetTrust {
+        /// Address of the position token.
+        #[clap(long)]
+        position: String,
+
+        /// Trust the position. Omit to distrust it.
+        #[clap(long)]
+        trust: bool,
+    },
+    SetFeesDistributor {
+        /// New fees distributor as a hex encoded 32 byte word.
+        #[clap(long)]
+        new_fees_distributor: String,
+    },
+    InitiateShutdown {
+        /// Exit the current position in the process.
+        #[clap(short = 'e', long)]
+        empty_position: bool,
+    },
+    LiftShutdown,
+    TransferOwnership {
+        /// Address of the new owner.
+        #[clap(long)]
+        new_owner: String,
+    },
+    /// Run several functions in order, in a single transaction.
+    Multicall {
+        /// A function call as it would be given to this command, such as "set-deposit-limit
+        /// --limit 1000". Repeat for each call, in order.
+        #[clap(long = "call")]
+        calls: Vec<String>,
+    },
+}
+
+impl AaveV2StablecoinFunction {
+    fn function(&self) -> Result<Function, String> {
+        Ok(match self {
+            Self::Accrue => Function::Accrue(aave_v2_stablecoin::Accrue {}),
+            Self::ClaimAndUnstake => {
+                Function::ClaimAndUnstake(aave_v2_stablecoin::ClaimAndUnstake {})
+            }
+            Self::EnterPosition => Function::EnterPosition(aave_v2_stablecoin::EnterPosition {}),
+            Self::EnterPositionWithAssets { assets } => {
+                Function::EnterPositionWithAssets(aave_v2_stablecoin::EnterPositionWithAssets {
+                    assets: assets.clone(),
+                })
+            }
+            Self::ExitPosition => Function::ExitPosition(aave_v2_stablecoin::ExitPosition {}),
+            Self::ExitPositionWithAssets { assets } => {
+                Function::ExitPositionWithAssets(aave_v2_stablecoin::ExitPositionWithAssets {
+                    assets: assets.clone(),
+                })
+            }
+            Self::Rebalance {
+                route,
+                swaps,
+                min_assets_out,
+            } => Function::Rebalance(aave_v2_stablecoin::Rebalance {
+                route: route.clone(),
+                swap_params: swaps
+                    .iter()
+                    .map(|s| parse_swap(s))
+                    .collect::<Result<_, _>>()?,
+                min_assets_out: min_assets_out.clone(),
+            }),
+            Self::Reinvest { min_assets_out } => Function::Reinvest(aave_v2_stablecoin::Reinvest {
+                min_assets_out: min_assets_out.clone(),
+            }),
+            Self::SetAccrualPeriod { new_accrual_period } => {
+                Function::SetAccrualPeriod(aave_v2_stablecoin::SetAccrualPeriod {
+                    new_accrual_period: *new_accrual_period,
+                })
+            }
+            Self::SetDepositLimit { limit } => {
+                Function::SetDepositLimit(aave_v2_stablecoin::SetDepositLimit {
+                    limit: limit.clone(),
+                })
+            }
+            Self::SetLiquidityLimit { limit } => {
+                Function::SetLiquidityLimit(aave_v2_stablecoin::SetLiquidityLimit {
+                    limit: limit.clone(),
+                })
+            }
+            Self::SendFees => Function::SendFees(aave_v2_stablecoin::SendFees {}),
+            Self::Sweep { token, to } => Function::Sweep(aave_v2_stablecoin::Sweep {
+                token: token.clone(),
+                to: to.clone(),
+            }),
+            Self::SetTrust { position, trust } => {
+                Function::SetTrust(aave_v2_stablecoin::SetTrust {
+                    position: position.clone(),
+                    trust: *trust,
+                })
+            }
+            Self::SetFeesDistributor {
+                new_fees_distributor,
+            } => Function::SetFeesDistributor(aave_v2_stablecoin::SetFeesDistributor {
+                new_fees_distributor: new_fees_distributor.clone(),
+            }),
+            Self::InitiateShutdown { empty_position } => {
+                Function::InitiateShutdown(aave_v2_stablecoin::InitiateShutdown {
+                    empty_position: *empty_position,
+                })
+            }
+            Self::LiftShutdown => Function::LiftShutdown(aave_v2_stablecoin::LiftShutdown {}),
+            Self::TransferOwnership { new_owner } => {
+                Function::TransferOwnership(aave_v2_stablecoin::TransferOwnership {
+                    new_owner: new_owner.clone(),
+                })
+            }
+            Self::Multicall { calls } => Function::Multicall(aave_v2_stablecoin::Multicall {
+                calls: calls
+                    .iter()
+                    .map(|c| parse_call(c))
+                    .collect::<Result<_, _>>()?,
+            }),
+        })
+    }
+}
+
+impl PreviewCmd {
+    fn request(&self) -> Result<PreviewRequest, String> {
+        let call_data = match &self.call {
+            CellarCall::AaveV2StablecoinCellar(f) => CallData::AaveV2Stablecoin(AaveV2Stablecoin {
+                function: Some(f.function()?),
+            }),
+        };
+
+        Ok(PreviewRequest {
+            request: Some(SubmitRequest {
+                cellar_id: self.cellar_id.clone(),
+                call_data: Some(call_data),
+                idempotency_key: String::new(),
+            }),
+        })
+    }
+}
+
+impl Runnable for PreviewCmd {
+    fn run(&self) {
+        let request = self.request().unwrap_or_else(|err| {
+            status_err!("{}", err);
+            std::process::exit(1);
+        });
+
+        abscissa_tokio::run_with_actix(&APP, async {
+            let mut client = self.client.connect().await.unwrap_or_else(|err| {
+                status_err!("{}", err);
+                std::process::exit(1);
+            });
+            let preview = client
+                .preview(request)
+                .await
+                .unwrap_or_else(|err| {
+                    status_err!("request rejected: {}", err.message());
+                    std::process::exit(1);
+                })
+                .into_inner();
+
+            let cork = preview.cork.unwrap_or_default();
+            println!("cork ID: {}", preview.cork_id);
+            println!("target contract: {}", cork.target_contract_address);
+            println!("function: {}", preview.signature);
+            println!("selector: 0x{}", preview.selector);
+            println!("arguments: {}", preview.arguments);
+            println!("encoded call: 0x{}", preview.encoded_call);
+        })
+        .unwrap_or_else(|e| {
+            status_err!("executor exited with error: {}", e);
+            std::process::exit(1);
+        });
+    }
+}
+
+/// Parses a call wrapped by a multicall, given as the function's subcommand and its arguments
+fn parse_call(call: &str) -> Result<AaveV2Stablecoin, String> {
+    let args = std::iter::once("multicall").chain(call.split_whitespace());
+    let function = AaveV2StablecoinFunction::try_parse_from(args)
+        .map_err(|err| format!("invalid multicall call {}: {}", call, err))?;
+    if let AaveV2StablecoinFunction::Multicall { .. } = function {
+        return Err("a multicall can't wrap another multicall".to_string());
+    }
+
+    Ok(AaveV2Stablecoin {
+        function: Some(function.function()?),
+    })
+}
+
+/// Parses a swap given as in_index:out_index:swap_type
+fn parse_swap(swap: &str) -> Result<SwapParams, String> {
+    let invalid = || {
+        format!(
+            "invalid swap {}, expected in_index:out_index:swap_type",
+            swap
+        )
+    };
+    let parts = swap
+        .split(':')
+        .map(|p| p.parse::<u64>().map_err(|_| invalid()))
+        .collect::<Result<Vec<u64>, String>>()?;
+    match parts[..] {
+        [in_index, out_index, swap_type] => Ok(SwapParams {
+            in_index,
+            out_index,
+            swap_type,
+        }),
+        _ => Err(invalid()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_multicall_calls() {
+        let call = parse_call("set-deposit-limit --limit 1000").unwrap();
+        match call.function {
+            Some(Function::SetDepositLimit(f)) => assert_eq!(f.limit, "1000"),
+            f => panic!("unexpected function {:?}", f),
+        }
+
+        assert!(parse_call("accrue").is_ok());
+        assert!(parse_call("set-deposit-limit").is_err());
+        assert!(parse_call("multicall --call accrue").is_err());
+    }
+}
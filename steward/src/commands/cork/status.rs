@@ -0,0 +1,71 @@
+use crate::{application::APP, cork, prelude::*};
+use abscissa_core::{clap::Parser, Command, Runnable};
+use somm_proto::cork::Cork;
+
+/// Cork status subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "\nReports the vote progress of a cork in the cork module and whether it has been executed on Ethereum. The cork is identified either by its ID, or by the target cellar and hex encoded contract call."
+)]
+pub struct StatusCmd {
+    /// Hex encoded cork ID.
+    #[clap(short = 'i', long, conflicts_with_all = &["contract", "encoded_call"])]
+    cork_id: Option<String>,
+
+    /// Target contract of the cork.
+    #[clap(short, long, requires = "encoded_call")]
+    contract: Option<String>,
+
+    /// Hex encoded contract call of the cork.
+    #[clap(short, long, requires = "contract")]
+    encoded_call: Option<String>,
+}
+
+impl StatusCmd {
+    fn cork_id(&self) -> Result<String, String> {
+        if let Some(id) = &self.cork_id {
+            return cork::status::normalize_cork_id(id).map_err(|err| err.to_string());
+        }
+
+        match (&self.contract, &self.encoded_call) {
+            (Some(contract), Some(encoded_call)) => {
+                let encoded_call = hex::decode(encoded_call.trim_start_matches("0x"))
+                    .map_err(|err| format!("invalid encoded call: {}", err))?;
+                let cork = Cork {
+                    encoded_contract_call: encoded_call,
+                    target_contract_address: contract.clone(),
+                };
+                cork::cork_id(&cork).map_err(|err| err.to_string())
+            }
+            _ => Err("either --cork-id or both --contract and --encoded-call are required".into()),
+        }
+    }
+}
+
+impl Runnable for StatusCmd {
+    fn run(&self) {
+        let cork_id = self.cork_id().unwrap_or_else(|err| {
+            status_err!("{}", err);
+            std::process::exit(1);
+        });
+
+        abscissa_tokio::run_with_actix(&APP, async {
+            let status = cork::status::get_cork_status(&cork_id)
+                .await
+                .unwrap_or_else(|err| {
+                    status_err!("failed to query cork status: {}", err);
+                    std::process::exit(1);
+                });
+
+            println!("cork ID: {}", status.cork_id);
+            println!("submitted votes: {}", status.submitted_votes);
+            println!("scheduled height: {}", status.scheduled_height);
+            println!("vote power: {}/{}", status.vote_power, status.total_power);
+            println!("executed: {}", status.executed);
+        })
+        .unwrap_or_else(|e| {
+            status_err!("executor exited with error: {}", e);
+            std::process::exit(1);
+        });
+    }
+}
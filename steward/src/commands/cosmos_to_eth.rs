@@ -1,11 +1,11 @@
-use crate::application::APP;
+use crate::{application::APP, connections};
 use abscissa_core::{clap::Parser, status_err, Application, Command, Runnable};
 use clarity::Uint256;
 use deep_space::coin::Coin;
 use ethers::types::Address as EthAddress;
 use gravity_bridge::cosmos_gravity::send::{send_request_batch_tx, send_to_eth};
 use gravity_bridge::gravity_proto::gravity::DenomToErc20Request;
-use gravity_bridge::gravity_utils::connection_prep::{check_for_fee_denom, create_rpc_connections};
+use gravity_bridge::gravity_utils::connection_prep::check_for_fee_denom;
 use std::{process::exit, time::Duration};
 
 const TIMEOUT: Duration = Duration::from_secs(60);
@@ -72,18 +72,12 @@ impl Runnable for CosmosToEthCmd {
 
         let cosmos_prefix = config.cosmos.prefix.trim();
         let cosmos_address = cosmos_key.to_address(cosmos_prefix).unwrap();
-        let cosmos_grpc = config.cosmos.grpc.trim();
         println!("Sending from Cosmos address {}", cosmos_address);
         abscissa_tokio::run_with_actix(&APP, async {
-        let connections = create_rpc_connections(
-            cosmos_prefix.to_string(),
-            Some(cosmos_grpc.to_string()),
-            None,
-            TIMEOUT,
-        )
-        .await;
-        let contact = connections.contact.unwrap();
-        let mut grpc = connections.grpc.unwrap();
+        let contact = connections::contact(TIMEOUT).expect("Could not create contact");
+        let mut grpc = connections::gravity_query_client()
+            .await
+            .expect("Could not connect to cosmos grpc");
         let res = grpc
             .denom_to_erc20(DenomToErc20Request {
                 denom: denom.clone(),
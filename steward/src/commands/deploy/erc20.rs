@@ -1,9 +1,9 @@
-use crate::{application::APP, prelude::*};
+use crate::{application::APP, connections, prelude::*, utils::get_eth_provider};
 use abscissa_core::{clap::Parser, Command, Runnable};
 use ethers::prelude::{Middleware, Signer, SignerMiddleware};
 use gravity_bridge::ethereum_gravity::deploy_erc20::deploy_erc20;
 use gravity_bridge::gravity_proto::gravity::{DenomToErc20ParamsRequest, DenomToErc20Request};
-use gravity_bridge::gravity_utils::connection_prep::{check_for_eth, create_rpc_connections};
+use gravity_bridge::gravity_utils::connection_prep::check_for_eth;
 use gravity_bridge::gravity_utils::ethereum::{downcast_to_u64, format_eth_hash};
 use std::convert::TryFrom;
 use std::process::exit;
@@ -52,15 +52,9 @@ impl Erc20 {
             .expect("Could not parse gravity contract address");
 
         let timeout = Duration::from_secs(500);
-        let connections = create_rpc_connections(
-            config.cosmos.prefix.clone(),
-            Some(config.cosmos.grpc.clone()),
-            Some(config.ethereum.rpc.clone()),
-            timeout,
-        )
-        .await;
-
-        let provider = connections.eth_provider.clone().unwrap();
+        let provider = get_eth_provider()
+            .await
+            .expect("Could not create ethereum provider");
         let chain_id = provider
             .get_chainid()
             .await
@@ -70,7 +64,9 @@ impl Erc20 {
         let eth_client =
             SignerMiddleware::new(provider, ethereum_wallet.clone().with_chain_id(chain_id));
         let eth_client = Arc::new(eth_client);
-        let mut grpc = connections.grpc.clone().unwrap();
+        let mut grpc = connections::gravity_query_client()
+            .await
+            .expect("Could not connect to cosmos grpc");
 
         check_for_eth(eth_client.address(), eth_client.clone()).await;
 
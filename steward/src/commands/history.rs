@@ -0,0 +1,153 @@
+use super::client::ClientArgs;
+use crate::{application::APP, prelude::*};
+use abscissa_core::{clap::Parser, Command, Runnable};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
+use steward_proto::steward::{GetSubmissionHistoryRequest, SubmissionRecord};
+
+/// History subcommand
+#[derive(Command, Debug, Parser)]
+#[clap(
+    long_about = "\nLists past submissions recorded in the submission journal of a running Steward server. Times are either RFC 3339 timestamps or durations before now such as 30m, 24h or 7d. The server requires a client certificate signed by its client CA."
+)]
+pub struct HistoryCmd {
+    /// Only list submissions for this cellar.
+    #[clap(short = 'i', long)]
+    cellar_id: Option<String>,
+
+    /// Only list submissions received at or after this time.
+    #[clap(short, long)]
+    since: Option<String>,
+
+    /// Only list submissions received before this time.
+    #[clap(short, long)]
+    until: Option<String>,
+
+    /// Only list submissions with this status: pending, sending, succeeded, failed or unknown.
+    #[clap(long)]
+    status: Option<String>,
+
+    /// Maximum number of submissions to list.
+    #[clap(short, long, default_value = "100")]
+    limit: u32,
+
+    #[clap(flatten)]
+    client: ClientArgs,
+}
+
+impl HistoryCmd {
+    fn request(&self) -> Result<GetSubmissionHistoryRequest, String> {
+        Ok(GetSubmissionHistoryRequest {
+            cellar_id: self.cellar_id.clone().unwrap_or_default(),
+            start_time: match &self.since {
+                Some(t) => parse_time(t)?,
+                None => 0,
+            },
+            end_time: match &self.until {
+                Some(t) => parse_time(t)?,
+                None => 0,
+            },
+            status: self.status.clone().unwrap_or_default(),
+            limit: self.limit,
+        })
+    }
+}
+
+impl Runnable for HistoryCmd {
+    fn run(&self) {
+        let request = self.request().unwrap_or_else(|err| {
+            status_err!("{}", err);
+            std::process::exit(1);
+        });
+
+        abscissa_tokio::run_with_actix(&APP, async {
+            let mut client = self.client.connect().await.unwrap_or_else(|err| {
+                status_err!("{}", err);
+                std::process::exit(1);
+            });
+            let submissions = client
+                .get_submission_history(request)
+                .await
+                .unwrap_or_else(|err| {
+                    status_err!("failed to query submission history: {}", err.message());
+                    std::process::exit(1);
+                })
+                .into_inner()
+                .submissions;
+
+            if submissions.is_empty() {
+                println!("no matching submissions");
+            }
+            for submission in submissions {
+                print_submission(&submission);
+            }
+        })
+        .unwrap_or_else(|e| {
+            status_err!("executor exited with error: {}", e);
+            std::process::exit(1);
+        });
+    }
+}
+
+fn print_submission(submission: &SubmissionRecord) {
+    println!(
+        "submission {} at {}: {}",
+        submission.id,
+        format_time(submission.created_at),
+        submission.status
+    );
+    println!("  cellar ID: {}", submission.cellar_id);
+    if !submission.function.is_empty() {
+        println!("  call: {} {}", submission.function, submission.arguments);
+    }
+    if !submission.cork_id.is_empty() {
+        println!("  cork ID: {}", submission.cork_id);
+    }
+    if !submission.tx_hash.is_empty() {
+        println!(
+            "  tx hash: {} at height {}",
+            submission.tx_hash, submission.height
+        );
+    }
+    if !submission.error.is_empty() {
+        println!("  error: {}", submission.error);
+    }
+    if !submission.idempotency_key.is_empty() {
+        println!("  idempotency key: {}", submission.idempotency_key);
+    }
+}
+
+/// Parses an RFC 3339 timestamp, or a duration before now with an s, m, h or d suffix, into a
+/// Unix timestamp
+fn parse_time(time: &str) -> Result<i64, String> {
+    if let Ok(t) = DateTime::parse_from_rfc3339(time) {
+        return Ok(t.timestamp());
+    }
+
+    let invalid = || {
+        format!(
+            "invalid time {}, expected an RFC 3339 timestamp or a duration such as 24h",
+            time
+        )
+    };
+    if time.len() < 2 || !time.is_ascii() {
+        return Err(invalid());
+    }
+    let (amount, unit) = time.split_at(time.len() - 1);
+    let amount = amount.parse::<i64>().map_err(|_| invalid())?;
+    let duration = match unit {
+        "s" => Duration::seconds(amount),
+        "m" => Duration::minutes(amount),
+        "h" => Duration::hours(amount),
+        "d" => Duration::days(amount),
+        _ => return Err(invalid()),
+    };
+
+    Ok((Utc::now() - duration).timestamp())
+}
+
+fn format_time(timestamp: i64) -> String {
+    match NaiveDateTime::from_timestamp_opt(timestamp, 0) {
+        Some(t) => DateTime::<Utc>::from_utc(t, Utc).to_rfc3339(),
+        None => timestamp.to_string(),
+    }
+}
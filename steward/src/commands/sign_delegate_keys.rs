@@ -1,4 +1,4 @@
-use crate::{application::APP, prelude::*};
+use crate::{application::APP, connections, prelude::*};
 use abscissa_core::{clap::Parser, Application, Command, Runnable};
 use gravity_bridge::gravity_proto::gravity as proto;
 use std::time::Duration;
@@ -27,12 +27,7 @@ impl Runnable for SignDelegateKeysCmd {
                 Some(nonce) => nonce,
                 None => {
                     let timeout = Duration::from_secs(10);
-                    let contact = deep_space::Contact::new(
-                        &config.cosmos.grpc,
-                        timeout,
-                        &config.cosmos.prefix,
-                    )
-                    .expect("Could not create contact");
+                    let contact = connections::contact(timeout).expect("Could not create contact");
 
                     let account_info = contact.get_account_info(address).await;
                     let account_info = account_info.expect("Did not receive account info");
@@ -2,23 +2,73 @@
 
 /// App-local prelude includes `app_reader()`/`app_writer()`/`app_config()`
 /// accessors along with logging macros. Customize as you see fit.
-use crate::{application::APP, config::StewardConfig, cork::CorkHandler, prelude::*, server};
+use crate::{
+    application::APP,
+    authorization,
+    cellar_query::CellarQueryHandler,
+    cellars,
+    config::{delegate_address, StewardConfig},
+    cork::{self, CorkHandler},
+    eth_send, guardrails, health, journal, metrics,
+    prelude::*,
+    server,
+};
 use abscissa_core::{clap::Parser, config, Command, FrameworkError, Runnable};
 use std::result::Result;
-use steward_proto::steward::contract_call_server::ContractCallServer;
+use steward_proto::steward::{
+    cellar_query_server::CellarQueryServer, contract_call_server::ContractCallServer,
+};
+use tokio::net::TcpListener;
 
 /// Cosmos Signer, start allocation module
 #[derive(Command, Debug, Parser)]
 #[clap(
-    long_about = "DESCRIPTION \n\n Cosmos mode, run Steward as a server.\n This command runs Steward as a server that will send updates to the Sommelier chain."
+    long_about = "DESCRIPTION \n\n Cosmos mode, run Steward as a server.\n This command runs Steward as a server that will send updates to the Sommelier chain.\n In test mode, updates are instead sent directly to the target Cellar on Ethereum, signed by a single Ethereum key."
 )]
-pub struct StartCmd;
+pub struct StartCmd {
+    /// Send cellar function calls directly to Ethereum instead of submitting corks to Sommelier.
+    #[clap(long)]
+    test_mode: bool,
+
+    /// Ethereum keyname from keystore used to sign transactions in test mode.
+    #[clap(short = 'e', long)]
+    ethereum_key: Option<String>,
+}
 
 impl Runnable for StartCmd {
     /// Start the application.
     fn run(&self) {
         let config = APP.config();
         info!("Starting application");
+        if config.test_mode.enabled {
+            if config.test_mode.ethereum_key.is_empty() {
+                status_err!("test mode requires an ethereum key. set test_mode.ethereum_key in the config or use --ethereum-key");
+                std::process::exit(1)
+            }
+            if let Err(err) = eth_send::load_wallet() {
+                status_err!("{}", err);
+                std::process::exit(1)
+            }
+            warn!("running in test mode: cellar calls will be sent directly to ethereum");
+        }
+        info!(
+            "supported cellar adapters: {}",
+            cellars::REGISTRY.names().join(", ")
+        );
+        // Open the journal and load the authorization policy and guardrails up front so that bad
+        // config fails at startup rather than on the first request
+        if let Err(err) = journal::load() {
+            status_err!("failed to open submission journal: {}", err);
+            std::process::exit(1)
+        }
+        if let Err(err) = authorization::load() {
+            status_err!("failed to load authorization policy: {}", err);
+            std::process::exit(1)
+        }
+        if let Err(err) = guardrails::load() {
+            status_err!("invalid guardrails config: {}", err);
+            std::process::exit(1)
+        }
         abscissa_tokio::run(&APP, async {
             // Reflection required for certain clients to function... such as grpcurl
             let contents = server::DESCRIPTOR.to_vec();
@@ -37,15 +87,34 @@ impl Runnable for StartCmd {
                     std::process::exit(1)
                 });
 
+            // Approved cellar IDs are only checked when submitting corks to Sommelier
+            if !config.test_mode.enabled {
+                // A missing delegate key is reported by the readiness checks
+                match delegate_address() {
+                    Ok(address) => info!("delegate address: {}", address),
+                    Err(err) => error!("{}", err),
+                }
+                tokio::spawn(cork::cache::run_refresher());
+            }
+            let (health_reporter, health_service) = tonic_health::server::health_reporter();
+            tokio::spawn(health::run_checks(health_reporter));
+            tokio::spawn(metrics::serve(config.metrics.listen_addr));
+
+            tokio::spawn(server_config.tls.clone().run_reloader(config.clone()));
+            let listener = TcpListener::bind(server_config.address)
+                .await
+                .unwrap_or_else(|err| {
+                    status_err!("failed to bind {}: {}", server_config.address, err);
+                    std::process::exit(1)
+                });
+
             info!("listening on {}", server_config.address);
             if let Err(err) = tonic::transport::Server::builder()
-                .tls_config(server_config.tls_config)
-                .unwrap_or_else(|err| {
-                    panic!("{:?}", err);
-                })
                 .add_service(ContractCallServer::new(CorkHandler))
+                .add_service(CellarQueryServer::new(CellarQueryHandler))
+                .add_service(health_service)
                 .add_service(proto_descriptor_service)
-                .serve(server_config.address)
+                .serve_with_incoming(server::incoming(listener, server_config.tls))
                 .await
             {
                 status_err!("server error: {}", err);
@@ -63,7 +132,15 @@ impl config::Override<StewardConfig> for StartCmd {
     // Process the given command line options, overriding settings from
     // a configuration file using explicit flags taken from command-line
     // arguments.
-    fn override_config(&self, config: StewardConfig) -> Result<StewardConfig, FrameworkError> {
+    fn override_config(&self, mut config: StewardConfig) -> Result<StewardConfig, FrameworkError> {
+        if self.test_mode {
+            config.test_mode.enabled = true;
+        }
+
+        if let Some(key) = &self.ethereum_key {
+            config.test_mode.ethereum_key = key.clone();
+        }
+
         Ok(config)
     }
 }
@@ -3,7 +3,10 @@
 //! See instructions in `commands.rs` to specify the path to your
 //! application's configuration file and/or command-line options
 //! for specifying it.
-use crate::prelude::APP;
+use crate::{
+    error::{Error, ErrorKind},
+    prelude::APP,
+};
 use abscissa_core::Application;
 use deep_space::{Address, PrivateKey};
 use ethers::signers::LocalWallet as EthWallet;
@@ -14,39 +17,77 @@ use signatory::FsKeyStore;
 use std::{net::SocketAddr, path::Path};
 
 lazy_static! {
-    pub static ref DELEGATE_KEY: PrivateKey = {
+    static ref DELEGATE: Result<(PrivateKey, Address), String> = {
         let config = APP.config();
-        let name = &config.keys.delegate_key;
-        config.load_deep_space_key(name.clone())
-    };
-    pub static ref DELEGATE_ADDRESS: Address = {
-        let config = APP.config();
-        DELEGATE_KEY
-            .to_address(&config.cosmos.prefix)
-            .expect("failed to derive delegate address from key. make sure the cosmos.prefix field is set in your config.")
+        config
+            .try_load_delegate(config.keys.delegate_key.clone())
+            .map_err(|err| err.to_string())
     };
 }
 
+/// The key corks are signed with, loaded from the keystore on first use
+pub fn delegate_key() -> Result<&'static PrivateKey, Error> {
+    delegate().map(|(key, _)| key)
+}
+
+/// The address of the delegate key, derived with the configured Cosmos prefix
+pub fn delegate_address() -> Result<&'static Address, Error> {
+    delegate().map(|(_, address)| address)
+}
+
+fn delegate() -> Result<&'static (PrivateKey, Address), Error> {
+    DELEGATE.as_ref().map_err(|err| {
+        ErrorKind::KeysError
+            .context(format!("failed to load delegate key: {}", err))
+            .into()
+    })
+}
+
 /// Steward Configuration
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct StewardConfig {
     pub keystore: String,
+    pub cellar_id_cache: CellarIdCacheSection,
     pub cosmos: CosmosSection,
     pub ethereum: EthereumSection,
     pub gravity: GravitySection,
+    // An empty array can't be serialized after the tables above
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub guardrails: Vec<GuardrailsSection>,
+    pub journal: JournalSection,
     pub keys: KeysConfig,
     pub metrics: MetricsSection,
+    pub rate_limit: RateLimitSection,
     pub server: ServerSection,
+    pub test_mode: TestModeSection,
 }
 
 impl StewardConfig {
     fn load_secret_key(&self, name: String) -> k256::elliptic_curve::SecretKey<k256::Secp256k1> {
+        self.try_load_secret_key(name)
+            .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn try_load_secret_key(
+        &self,
+        name: String,
+    ) -> Result<k256::elliptic_curve::SecretKey<k256::Secp256k1>, Error> {
         let keystore = Path::new(&self.keystore);
-        let keystore = FsKeyStore::create_or_open(keystore).expect("Could not open keystore");
-        let name = name.parse().expect("Could not parse name");
-        let key = keystore.load(&name).expect("Could not load key");
-        key.to_pem().parse().expect("Could not parse pem")
+        let keystore = FsKeyStore::create_or_open(keystore).map_err(|err| {
+            ErrorKind::KeysError.context(format!("Could not open keystore: {}", err))
+        })?;
+        let key_name = name.parse().map_err(|err| {
+            ErrorKind::KeysError.context(format!("Could not parse name: {}", err))
+        })?;
+        let key = keystore.load(&key_name).map_err(|err| {
+            ErrorKind::KeysError.context(format!("Could not load key {}: {}", name, err))
+        })?;
+        key.to_pem().parse().map_err(|err| {
+            ErrorKind::KeysError
+                .context(format!("Could not parse pem of key {}: {}", name, err))
+                .into()
+        })
     }
 
     pub fn load_clarity_key(&self, name: String) -> clarity::PrivateKey {
@@ -60,6 +101,19 @@ impl StewardConfig {
         key.parse().expect("Could not parse private key")
     }
 
+    fn try_load_delegate(&self, name: String) -> Result<(PrivateKey, Address), Error> {
+        let key = self.try_load_secret_key(name)?.to_bytes();
+        let key: PrivateKey = deep_space::utils::bytes_to_hex_str(&key).parse()?;
+        let address = key.to_address(&self.cosmos.prefix).map_err(|err| {
+            ErrorKind::KeysError.context(format!(
+                "failed to derive delegate address from key, make sure the cosmos.prefix field is set in your config: {}",
+                err
+            ))
+        })?;
+
+        Ok((key, address))
+    }
+
     pub fn load_gravity_deep_space_key(&self, name: String) -> cosmos_gravity::crypto::PrivateKey {
         let key = self.load_secret_key(name).to_bytes();
         let key = deep_space::utils::bytes_to_hex_str(&key);
@@ -69,6 +123,10 @@ impl StewardConfig {
     pub fn load_ethers_wallet(&self, name: String) -> EthWallet {
         EthWallet::from(self.load_secret_key(name))
     }
+
+    pub fn try_load_ethers_wallet(&self, name: String) -> Result<EthWallet, Error> {
+        Ok(EthWallet::from(self.try_load_secret_key(name)?))
+    }
 }
 
 /// Default configuration settings.
@@ -79,12 +137,17 @@ impl Default for StewardConfig {
     fn default() -> Self {
         Self {
             keystore: String::new(),
+            cellar_id_cache: CellarIdCacheSection::default(),
             cosmos: CosmosSection::default(),
             ethereum: EthereumSection::default(),
             gravity: GravitySection::default(),
+            guardrails: Vec::new(),
+            journal: JournalSection::default(),
             keys: KeysConfig::default(),
             metrics: MetricsSection::default(),
+            rate_limit: RateLimitSection::default(),
             server: ServerSection::default(),
+            test_mode: TestModeSection::default(),
         }
     }
 }
@@ -93,8 +156,12 @@ impl Default for StewardConfig {
 #[serde(default)]
 pub struct ServerSection {
     pub address: String,
+    pub authorization_policy_path: Option<String>,
+    pub certificate_reload_period: u64,
     pub client_ca_cert_path: Option<String>,
+    pub crl_path: Option<String>,
     pub port: u16,
+    pub revoked_serials: Vec<String>,
     pub server_cert_path: String,
     pub server_key_path: String,
 }
@@ -103,14 +170,117 @@ impl Default for ServerSection {
     fn default() -> Self {
         Self {
             address: "0.0.0.0".to_string(),
+            authorization_policy_path: None,
+            certificate_reload_period: 30,
             client_ca_cert_path: None,
+            crl_path: None,
             port: 5734,
+            revoked_serials: Vec::new(),
             server_cert_path: "".to_owned(),
             server_key_path: "".to_owned(),
         }
     }
 }
 
+/// The cellar IDs approved by governance are cached and refreshed from the cork module every
+/// `refresh_period` seconds. If the cache is older than `max_staleness` seconds and can't be
+/// refreshed, requests are rejected rather than validated against it.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct CellarIdCacheSection {
+    pub refresh_period: u64,
+    pub max_staleness: u64,
+}
+
+impl Default for CellarIdCacheSection {
+    fn default() -> Self {
+        Self {
+            refresh_period: 30,
+            max_staleness: 300,
+        }
+    }
+}
+
+/// Validator-side limits on the arguments of calls to one cellar, checked before a call is signed.
+/// Function names are as they appear in the cellar ABI, amounts are decimal integer strings, and
+/// unset limits aren't checked.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct GuardrailsSection {
+    pub cellar_id: String,
+    #[serde(default)]
+    pub disabled_functions: Vec<String>,
+    pub max_deposit_limit: Option<String>,
+    pub max_liquidity_limit: Option<String>,
+    pub min_assets_out: Option<String>,
+    #[serde(default)]
+    pub allowed_route_addresses: Vec<String>,
+    #[serde(default)]
+    pub allowed_swap_types: Vec<u64>,
+}
+
+/// When enabled, every `Submit` request and its outcome is recorded in an embedded database at
+/// `path`, and requests carrying an idempotency key that already succeeded are answered from it.
+/// Submissions are kept for `retention_days`, or forever if zero.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct JournalSection {
+    pub enabled: bool,
+    pub path: String,
+    pub retention_days: u64,
+}
+
+impl Default for JournalSection {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: "steward_journal".to_owned(),
+            retention_days: 30,
+        }
+    }
+}
+
+/// Token bucket limits on contract call requests. Each client identity and each cellar has a bucket
+/// holding up to `*_burst` requests that refills at `*_requests_per_minute`. A rate of zero disables
+/// that limit.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RateLimitSection {
+    pub client_requests_per_minute: u32,
+    pub client_burst: u32,
+    pub cellar_requests_per_minute: u32,
+    pub cellar_burst: u32,
+}
+
+impl Default for RateLimitSection {
+    fn default() -> Self {
+        Self {
+            client_requests_per_minute: 0,
+            client_burst: 10,
+            cellar_requests_per_minute: 0,
+            cellar_burst: 5,
+        }
+    }
+}
+
+/// Test mode skips the Sommelier chain and Gravity bridge entirely, sending cellar function calls
+/// directly to the target contract on Ethereum signed by a single key.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct TestModeSection {
+    pub enabled: bool,
+    pub ethereum_key: String,
+}
+
+impl Default for TestModeSection {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            ethereum_key: "".to_owned(),
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(default)]
 pub struct KeysConfig {
@@ -131,6 +301,7 @@ pub struct EthereumSection {
     pub blocks_to_search: u64,
     pub gas_price_multiplier: f32,
     pub key_derivation_path: String,
+    pub preflight_calls: bool,
     pub rpc: String,
 }
 
@@ -140,6 +311,7 @@ impl Default for EthereumSection {
             blocks_to_search: 5000,
             gas_price_multiplier: 1.0f32,
             key_derivation_path: "m/44'/60'/0'/0/0".to_owned(),
+            preflight_calls: false,
             rpc: "http://localhost:8545".to_owned(),
         }
     }
@@ -164,23 +336,53 @@ impl Default for GravitySection {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 #[serde(default, deny_unknown_fields)]
 pub struct CosmosSection {
+    /// Replaces `gas_adjustment` for cork transactions, which are charged their fee without being
+    /// retried if they run out of gas
+    pub cork_gas_adjustment: f64,
     pub gas_adjustment: f64,
     pub grpc: String,
     pub key_derivation_path: String,
     pub prefix: String,
     pub msg_batch_size: u32,
+    pub max_fee: Option<u64>,
     pub gas_price: GasPrice,
+    pub broadcast: BroadcastSection,
 }
 
 impl Default for CosmosSection {
     fn default() -> Self {
         Self {
+            cork_gas_adjustment: 1.2f64,
             gas_adjustment: 1.0f64,
             grpc: "https://127.0.0.1:9090".to_owned(),
             key_derivation_path: "m/44'/118'/0'/0/0".to_owned(),
             prefix: "somm".to_owned(),
             msg_batch_size: 5,
+            max_fee: None,
             gas_price: GasPrice::default(),
+            broadcast: BroadcastSection::default(),
+        }
+    }
+}
+
+/// How cork transactions are broadcast to the Sommelier chain, and how failures to get them
+/// committed are retried. `mode` is one of "sync", "async" or "block".
+#[derive(Clone, Debug, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct BroadcastSection {
+    pub mode: String,
+    pub timeout: u64,
+    pub retries: u32,
+    pub backoff_ms: u64,
+}
+
+impl Default for BroadcastSection {
+    fn default() -> Self {
+        Self {
+            mode: "sync".to_owned(),
+            timeout: 60,
+            retries: 2,
+            backoff_ms: 1000,
         }
     }
 }
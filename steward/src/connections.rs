@@ -0,0 +1,131 @@
+//! Long-lived connections to the Cosmos node, shared across requests so that a burst of requests
+//! doesn't open a new connection for each one. A `Channel` reconnects on its own once established;
+//! the initial connection is retried with exponential backoff.
+//!
+//! deep_space's `Contact`, used to build and broadcast transactions, opens its own connection for
+//! each call it makes, so it is created per use with the caller's timeout rather than shared.
+use crate::{
+    error::{Error, ErrorKind},
+    prelude::APP,
+};
+use abscissa_core::{
+    tracing::log::{debug, warn},
+    Application,
+};
+use deep_space::Contact;
+use gravity_bridge::gravity_proto::{
+    cosmos_sdk_proto::cosmos::staking::v1beta1::query_client::QueryClient as StakingQueryClient,
+    gravity::query_client::QueryClient as GravityQueryClient,
+};
+use lazy_static::lazy_static;
+use somm_proto::cork::query_client::QueryClient as CorkQueryClient;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tonic::transport::{Channel, Endpoint};
+
+/// Timeout of the calls a `Contact` makes while submitting a cork
+pub const MESSAGE_TIMEOUT: Duration = Duration::from_secs(10);
+const TCP_KEEPALIVE: Duration = Duration::from_secs(60);
+const INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(8);
+const MAX_CONNECT_ATTEMPTS: u32 = 5;
+
+lazy_static! {
+    static ref CHANNEL: Mutex<Option<Channel>> = Mutex::new(None);
+}
+
+/// Returns the shared channel to the Cosmos node's gRPC endpoint, connecting if necessary
+pub async fn cosmos_channel() -> Result<Channel, Error> {
+    let mut channel = CHANNEL.lock().await;
+    if let Some(c) = channel.as_ref() {
+        return Ok(c.clone());
+    }
+
+    let c = connect_with_backoff(&APP.config().cosmos.grpc).await?;
+    *channel = Some(c.clone());
+
+    Ok(c)
+}
+
+/// Creates a `Contact` for building and broadcasting Cosmos transactions, whose calls to the node
+/// time out after `timeout`
+pub fn contact(timeout: Duration) -> Result<Contact, Error> {
+    let config = APP.config();
+    debug!(
+        "creating cosmos contact for {} with timeout {:?}",
+        config.cosmos.grpc, timeout
+    );
+
+    Ok(Contact::new(
+        &config.cosmos.grpc,
+        timeout,
+        &config.cosmos.prefix,
+    )?)
+}
+
+pub async fn cork_query_client() -> Result<CorkQueryClient<Channel>, Error> {
+    Ok(CorkQueryClient::new(cosmos_channel().await?))
+}
+
+pub async fn gravity_query_client() -> Result<GravityQueryClient<Channel>, Error> {
+    Ok(GravityQueryClient::new(cosmos_channel().await?))
+}
+
+pub async fn staking_query_client() -> Result<StakingQueryClient<Channel>, Error> {
+    Ok(StakingQueryClient::new(cosmos_channel().await?))
+}
+
+async fn connect_with_backoff(url: &str) -> Result<Channel, Error> {
+    let endpoint = Endpoint::from_shared(url.to_string())
+        .map_err(|err| ErrorKind::Config.context(format!("invalid grpc url {}: {}", url, err)))?
+        .tcp_keepalive(Some(TCP_KEEPALIVE));
+
+    let mut backoff = backoff_delays();
+    let mut attempt = 1;
+    loop {
+        debug!("connecting to {}, attempt {}", url, attempt);
+        match endpoint.connect().await {
+            Ok(channel) => return Ok(channel),
+            Err(err) if attempt < MAX_CONNECT_ATTEMPTS => {
+                let delay = backoff.next().unwrap_or(MAX_BACKOFF);
+                warn!(
+                    "failed to connect to {}, retrying in {:?}: {}",
+                    url, delay, err
+                );
+                tokio::time::sleep(delay).await;
+                attempt += 1;
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+}
+
+/// Delays between connection attempts, doubling from the initial backoff up to the maximum
+fn backoff_delays() -> impl Iterator<Item = Duration> {
+    std::iter::successors(Some(INITIAL_BACKOFF), |d| {
+        Some(std::cmp::min(*d * 2, MAX_BACKOFF))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn backoff_doubles_up_to_maximum() {
+        let delays: Vec<u64> = backoff_delays()
+            .take(MAX_CONNECT_ATTEMPTS as usize + 1)
+            .map(|d| d.as_millis() as u64)
+            .collect();
+
+        assert_eq!(delays, vec![500, 1000, 2000, 4000, 8000, 8000]);
+    }
+
+    #[test]
+    fn invalid_url_fails_without_retrying() {
+        let err = block_on(connect_with_backoff("not a url")).unwrap_err();
+
+        assert_eq!(*err.kind(), ErrorKind::Config);
+    }
+}
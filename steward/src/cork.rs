@@ -1,26 +1,44 @@
 use crate::{
-    cellars::{self, aave_v2_stablecoin},
-    config,
+    authorization,
+    cellars::REGISTRY,
+    config, connections,
     error::{Error, ErrorKind},
+    eth_send,
+    guardrails::{self, Guardrails},
+    journal::{self, Begin, Journal, Submission},
+    metrics,
     prelude::APP,
-    somm_send,
+    rate_limit,
+    somm_send::{self, BroadcastSettings, GasSettings},
+    utils::sp_call_error,
 };
 use abscissa_core::{
     tracing::log::{debug, error, info, warn},
     Application,
 };
-use deep_space::{Coin, Contact};
-use gravity_bridge::gravity_proto::cosmos_sdk_proto::cosmos::base::abci::v1beta1::TxResponse;
-use somm_proto::cork::{query_client::QueryClient as CorkQueryClient, Cork, QueryCellarIDsRequest};
-use std::time::Duration;
+use ethers::{types::H160, utils::keccak256};
+use gravity_bridge::gravity_proto::cosmos_sdk_proto::cosmos::{
+    base::abci::v1beta1::TxResponse, tx::v1beta1::BroadcastMode,
+};
+use somm_proto::cork::Cork;
+use std::{collections::HashSet, time::Duration};
 use steward_proto::{
     self,
-    steward::{self, submit_request::CallData::AaveV2Stablecoin, SubmitRequest, SubmitResponse},
+    steward::{
+        self, DecodeRequest, DecodeResponse, GetCorkStatusRequest, GetCorkStatusResponse,
+        GetSubmissionHistoryRequest, GetSubmissionHistoryResponse, PreviewRequest, PreviewResponse,
+        ScheduleCorkRequest, ScheduleCorkResponse, SubmitBatchRequest, SubmitBatchResponse,
+        SubmitBatchResult, SubmitRequest, SubmitResponse,
+    },
 };
 use tonic::{self, async_trait, Code, Request, Response, Status};
 
-const MESSAGE_TIMEOUT: Duration = Duration::from_secs(10);
-const CHAIN_PREFIX: &str = "somm";
+pub mod cache;
+pub mod decode;
+pub mod history;
+pub mod preflight;
+pub mod preview;
+pub mod status;
 
 pub struct CorkHandler;
 
@@ -30,116 +48,738 @@ impl steward::contract_call_server::ContractCall for CorkHandler {
         &self,
         request: Request<SubmitRequest>,
     ) -> Result<Response<SubmitResponse>, Status> {
+        let identities = authorization::client_identities(request.peer_certs());
+        if let Err(status) = rate_limit::check_client(&identities, 1) {
+            metrics::record_rejection(status.code());
+            return Err(status);
+        }
         let request = request.get_ref().to_owned();
-
-        // Check if cellar is governance approved before building cork
-        let config = APP.config();
-        let mut client = match CorkQueryClient::connect(config.cosmos.grpc.clone()).await {
-            Ok(c) => c,
+        let journal = journal::get();
+        // Clients without a certificate share the empty identity
+        let client = identities.first().map_or("", |i| i.as_str());
+        let begin = match journal {
+            Some(j) => j.begin(client, &request).await.map(Some),
+            None => Ok(None),
+        };
+        let mut submission = match begin {
+            Ok(Some(Begin::New(s))) => Some(s),
+            Ok(Some(Begin::Replay(s))) => {
+                info!(
+                    "replaying result of submission {} for idempotency key {}",
+                    s.id, s.idempotency_key
+                );
+                return Ok(Response::new(s.to_response()));
+            }
+            Ok(Some(Begin::InProgress)) => {
+                metrics::record_rejection(Code::Aborted);
+                return Err(Status::new(
+                    Code::Aborted,
+                    "a request with this idempotency key is already in progress",
+                ));
+            }
+            Ok(Some(Begin::Unknown(s))) => {
+                metrics::record_rejection(Code::FailedPrecondition);
+                return Err(Status::new(
+                    Code::FailedPrecondition,
+                    format!(
+                        "a request with this idempotency key may already have sent cork {}, check its status before retrying with a new key",
+                        s.cork_id
+                    ),
+                ));
+            }
+            Ok(Some(Begin::Conflict)) => {
+                metrics::record_rejection(Code::InvalidArgument);
+                return Err(Status::new(
+                    Code::InvalidArgument,
+                    "this idempotency key was already used for a different request",
+                ));
+            }
+            Ok(None) => None,
             Err(err) => {
-                error!("cork query client connection failed: {}", err);
+                error!("failed to record request in journal: {}", err);
                 return Err(Status::new(
                     Code::Internal,
-                    "failed to query chain to validate cellar id",
+                    "failed to record request in journal",
                 ));
             }
         };
 
-        debug!("checking if cellar ID is approved");
-        let ids = &client
-            .query_cellar_i_ds(QueryCellarIDsRequest {})
-            .await?
-            .get_ref()
-            .cellar_ids
-            .clone();
-        if !ids.contains(&request.cellar_id) {
-            info!(
-                "rejecting request for unapproved cellar {}",
-                request.cellar_id
-            );
+        let prepared = match check_cellar_approved(&request.cellar_id).await {
+            Ok(()) => {
+                let prepared = prepare_cork(request, &identities, &mut submission).await;
+                if let Err(status) = &prepared {
+                    metrics::record_rejection(status.code());
+                }
+                prepared
+            }
+            Err(status) => Err(status),
+        };
+        let (cork, cork_id) = match prepared {
+            Ok(c) => c,
+            Err(status) => {
+                // Nothing was sent, so the idempotency key can be used again
+                if let (Some(j), Some(s)) = (journal, submission.as_mut()) {
+                    s.failed(status.message());
+                    record_result(j, s).await;
+                }
+                return Err(status);
+            }
+        };
+
+        // Once the cork is handed off to be signed and broadcast it may reach the chain even if
+        // sending fails, so from here on the idempotency key stays claimed whatever the outcome.
+        // This is recorded first so that it holds even if Steward stops while sending.
+        if let (Some(j), Some(s)) = (journal, submission.as_mut()) {
+            s.sending();
+            if let Err(err) = j.update(s).await {
+                error!("failed to record submission {} in journal: {}", s.id, err);
+                return Err(Status::new(
+                    Code::Internal,
+                    "failed to record request in journal",
+                ));
+            }
+        }
+        let result = send_prepared_cork(cork, cork_id).await;
+        if let Err(status) = &result {
+            metrics::record_rejection(status.code());
+        }
+        if let (Some(j), Some(s)) = (journal, submission.as_mut()) {
+            match &result {
+                Ok(response) => s.succeeded(response),
+                Err(status) => s.unknown(status.message()),
+            }
+            record_result(j, s).await;
+        }
+
+        result.map(Response::new)
+    }
+
+    async fn submit_batch(
+        &self,
+        request: Request<SubmitBatchRequest>,
+    ) -> Result<Response<SubmitBatchResponse>, Status> {
+        let identities = authorization::client_identities(request.peer_certs());
+        let requests = request.get_ref().requests.to_owned();
+        if requests.is_empty() {
+            return Err(Status::new(
+                Code::InvalidArgument,
+                "batch contains no requests",
+            ));
+        }
+        if let Some(i) = requests.iter().position(|r| !r.idempotency_key.is_empty()) {
+            metrics::record_rejection(Code::InvalidArgument);
             return Err(Status::new(
-                Code::PermissionDenied,
+                Code::InvalidArgument,
                 format!(
-                    "cellar ID {} not approved by governance",
-                    &request.cellar_id
+                    "request {} in the batch has an idempotency key, which only Submit supports",
+                    i
                 ),
             ));
         }
+        // Each request in the batch counts against the client's limit
+        if let Err(status) = rate_limit::check_client(&identities, requests.len() as u32) {
+            metrics::record_rejection(status.code());
+            return Err(status);
+        }
+        let config = APP.config();
+        let duplicates = duplicate_cellars(&requests);
 
-        // Build and send cork
-        let cellar_id = request.cellar_id.clone();
-        let cork = match build_cork(request).await {
-            Ok(c) => c,
+        // Build every cork we can, recording a result for each request in order. Only the valid
+        // corks are sent.
+        let mut results = Vec::with_capacity(requests.len());
+        let mut corks = Vec::new();
+        for request in requests {
+            let cellar_id = request.cellar_id.clone();
+            // The cork module keeps one cork per validator per cellar, so a later cork for the
+            // same cellar would replace an earlier one
+            if duplicates.contains(&cellar_id.to_lowercase()) {
+                metrics::record_rejection(Code::InvalidArgument);
+                results.push(batch_failure(
+                    cellar_id,
+                    "cellar appears more than once in the batch, use a multicall to make several calls on one cellar".to_string(),
+                ));
+                continue;
+            }
+            if let Err(status) = check_cellar_approved(&cellar_id).await {
+                // Without the approved IDs no request in the batch can be validated
+                if status.code() == Code::Unavailable {
+                    return Err(status);
+                }
+                results.push(batch_failure(cellar_id, status.message().to_string()));
+                continue;
+            }
+
+            match build_cork(request).await {
+                Ok(cork) => {
+                    debug!("cork: {:?}", cork);
+                    let cork_id = match check_cork(&cork, &identities).await {
+                        Ok(id) => id,
+                        // Without Ethereum no cork in the batch can be simulated
+                        Err(status) if status.code() == Code::Unavailable => {
+                            metrics::record_rejection(status.code());
+                            return Err(status);
+                        }
+                        Err(status) => {
+                            metrics::record_rejection(status.code());
+                            results.push(batch_failure(cellar_id, status.message().to_string()));
+                            continue;
+                        }
+                    };
+                    corks.push((results.len(), cork));
+                    results.push(SubmitBatchResult {
+                        cellar_id,
+                        success: true,
+                        cork_id,
+                        ..Default::default()
+                    });
+                }
+                Err(err) => {
+                    warn!("failed to build cork for cellar {}: {}", cellar_id, err);
+                    metrics::record_rejection(Code::InvalidArgument);
+                    results.push(batch_failure(cellar_id, err.to_string()));
+                }
+            }
+        }
+
+        if corks.is_empty() {
+            info!("no valid corks in batch of {}", results.len());
+            return Ok(Response::new(SubmitBatchResponse { results }));
+        }
+
+        if config.test_mode.enabled {
+            // There is no batching on Ethereum so each call is sent as its own transaction
+            for (i, cork) in corks {
+                let cellar_id = cork.target_contract_address.clone();
+                match eth_send::send_contract_call(&cellar_id, cork.encoded_contract_call).await {
+                    Ok(receipt) => {
+                        info!(
+                            "test mode: sent contract call to {} in tx {:?}",
+                            cellar_id, receipt.transaction_hash
+                        );
+                        results[i].tx_hash = format!("{:?}", receipt.transaction_hash);
+                    }
+                    Err(err) => {
+                        error!("test mode: failed to send contract call: {}", err);
+                        metrics::record_rejection(Code::Internal);
+                        results[i].success = false;
+                        results[i].error = "failed to send contract call to ethereum".to_string();
+                    }
+                }
+            }
+
+            return Ok(Response::new(SubmitBatchResponse { results }));
+        }
+
+        let (indices, corks): (Vec<usize>, Vec<Cork>) = corks.into_iter().unzip();
+        let count = corks.len();
+        match send_corks(corks).await {
+            Ok(response) => {
+                info!(
+                    "submitted batch of {} corks in tx {}!",
+                    count, response.txhash
+                );
+                for i in indices {
+                    results[i].tx_hash = response.txhash.clone();
+                }
+            }
+            Err(err) => {
+                error!("failed to submit batch of {} corks: {}", count, err);
+                for i in indices {
+                    metrics::record_rejection(Code::Internal);
+                    results[i].success = false;
+                    results[i].error = "failed to send corks to sommelier".to_string();
+                }
+            }
+        }
+
+        Ok(Response::new(SubmitBatchResponse { results }))
+    }
+
+    async fn schedule_cork(
+        &self,
+        request: Request<ScheduleCorkRequest>,
+    ) -> Result<Response<ScheduleCorkResponse>, Status> {
+        let identities = authorization::client_identities(request.peer_certs());
+        let (request, block_height) =
+            match validate_schedule_request(request.get_ref().to_owned(), &identities) {
+                Ok(r) => r,
+                Err(status) => {
+                    metrics::record_rejection(status.code());
+                    return Err(status);
+                }
+            };
+        check_cellar_approved(&request.cellar_id).await?;
+
+        let result = schedule_request(request, block_height, &identities).await;
+        if let Err(status) = &result {
+            metrics::record_rejection(status.code());
+        }
+
+        result.map(Response::new)
+    }
+
+    async fn get_cork_status(
+        &self,
+        request: Request<GetCorkStatusRequest>,
+    ) -> Result<Response<GetCorkStatusResponse>, Status> {
+        let request = request.get_ref().to_owned();
+        let cork_id = if request.cork_id.is_empty() {
+            let cork = Cork {
+                encoded_contract_call: request.encoded_contract_call,
+                target_contract_address: request.cellar_id,
+            };
+            cork_id(&cork)
+        } else {
+            status::normalize_cork_id(&request.cork_id)
+        };
+        let cork_id = match cork_id {
+            Ok(id) => id,
+            Err(err) => return Err(Status::new(Code::InvalidArgument, err.to_string())),
+        };
+
+        match status::get_cork_status(&cork_id).await {
+            Ok(response) => Ok(Response::new(response)),
+            Err(err) => {
+                error!("failed to query status of cork {}: {}", cork_id, err);
+                Err(Status::new(Code::Internal, "failed to query cork status"))
+            }
+        }
+    }
+
+    async fn get_submission_history(
+        &self,
+        request: Request<GetSubmissionHistoryRequest>,
+    ) -> Result<Response<GetSubmissionHistoryResponse>, Status> {
+        // With an authorization policy, clients may only see their own submissions
+        let clients =
+            authorization::get().map(|_| authorization::client_identities(request.peer_certs()));
+        let journal = match journal::get() {
+            Some(j) => j,
+            None => {
+                return Err(Status::new(
+                    Code::FailedPrecondition,
+                    "the submission journal is not enabled",
+                ))
+            }
+        };
+
+        match history::get_submission_history(journal, request.get_ref().to_owned(), clients) {
+            Ok(response) => Ok(Response::new(response)),
+            Err(err) if *err.kind() == ErrorKind::ClientError => {
+                Err(Status::new(Code::InvalidArgument, err.to_string()))
+            }
             Err(err) => {
-                warn!("failed to build cork for cellar {}: {}", cellar_id, err);
-                return Err(Status::new(Code::InvalidArgument, err.to_string()));
+                error!("failed to query submission history: {}", err);
+                Err(Status::new(
+                    Code::Internal,
+                    "failed to query submission history",
+                ))
+            }
+        }
+    }
+
+    async fn preview(
+        &self,
+        request: Request<PreviewRequest>,
+    ) -> Result<Response<PreviewResponse>, Status> {
+        let identities = authorization::client_identities(request.peer_certs());
+        let request = match request.get_ref().request.to_owned() {
+            Some(r) => r,
+            None => {
+                return Err(Status::new(
+                    Code::InvalidArgument,
+                    "empty contract call request",
+                ))
             }
         };
-        debug!("cork: {:?}", cork);
 
-        if let Err(err) = send_cork(cork).await {
+        preview::preview(request, &identities)
+            .await
+            .map(Response::new)
+    }
+
+    async fn decode(
+        &self,
+        request: Request<DecodeRequest>,
+    ) -> Result<Response<DecodeResponse>, Status> {
+        match decode::decode(&request.get_ref().encoded_contract_call) {
+            Ok(call) => Ok(Response::new(DecodeResponse { call: Some(call) })),
+            Err(err) => Err(Status::new(Code::InvalidArgument, err.to_string())),
+        }
+    }
+}
+
+/// Checks that governance approved the cellar, counting the rejection if it didn't. Unapproved
+/// cellars are rejected with PermissionDenied, like clients denied by the authorization policy, so
+/// they're counted under their own reason rather than by their status code.
+async fn check_cellar_approved(cellar_id: &str) -> Result<(), Status> {
+    cache::check_cellar_approved(cellar_id)
+        .await
+        .map_err(|status| {
+            if status.code() == Code::PermissionDenied {
+                metrics::record_unapproved();
+            } else {
+                metrics::record_rejection(status.code());
+            }
+            status
+        })
+}
+
+/// Writes the outcome of a submission to the journal. The request has already completed, so a
+/// failure is only logged.
+async fn record_result(journal: &Journal, submission: &mut Submission) {
+    if let Err(err) = journal.update(submission).await {
+        error!(
+            "failed to record result of submission {} in journal: {}",
+            submission.id, err
+        );
+    }
+}
+
+/// Validates the request for an approved cellar and builds its cork, returning the cork and its
+/// ID. The journaled submission, if any, is updated with the cork as it's built.
+async fn prepare_cork(
+    request: SubmitRequest,
+    identities: &[String],
+    submission: &mut Option<Submission>,
+) -> Result<(Cork, String), Status> {
+    let cellar_id = request.cellar_id.clone();
+    let cork = match build_cork(request).await {
+        Ok(c) => c,
+        Err(err) => {
+            warn!("failed to build cork for cellar {}: {}", cellar_id, err);
+            return Err(Status::new(Code::InvalidArgument, err.to_string()));
+        }
+    };
+    debug!("cork: {:?}", cork);
+    if let Some(s) = submission.as_mut() {
+        s.encoded_call = hex::encode(&cork.encoded_contract_call);
+    }
+    let cork_id = check_cork(&cork, identities).await?;
+    if let Some(s) = submission.as_mut() {
+        s.cork_id = cork_id.clone();
+    }
+
+    Ok((cork, cork_id))
+}
+
+/// Sends a cork that passed `prepare_cork`
+async fn send_prepared_cork(cork: Cork, cork_id: String) -> Result<SubmitResponse, Status> {
+    let cellar_id = cork.target_contract_address.clone();
+    if APP.config().test_mode.enabled {
+        let receipt =
+            match eth_send::send_contract_call(&cellar_id, cork.encoded_contract_call).await {
+                Ok(r) => r,
+                Err(err) => {
+                    error!("test mode: failed to send contract call: {}", err);
+                    return Err(Status::new(
+                        Code::Internal,
+                        "failed to send contract call to ethereum",
+                    ));
+                }
+            };
+        info!(
+            "test mode: sent contract call to {} in tx {:?}",
+            cellar_id, receipt.transaction_hash
+        );
+
+        return Ok(SubmitResponse {
+            tx_hash: format!("{:?}", receipt.transaction_hash),
+            height: receipt.block_number.unwrap_or_default().as_u64(),
+            gas_used: receipt.gas_used.unwrap_or_default().as_u64(),
+            cellar_id,
+            cork_id,
+        });
+    }
+
+    let response = match send_cork(cork).await {
+        Ok(r) => r,
+        Err(err) => {
             error!("failed to submit cork: {}", err);
             return Err(Status::new(
                 Code::Internal,
                 "failed to send cork to sommelier",
             ));
         }
-        info!("submitted cork for {}!", cellar_id);
+    };
+    info!(
+        "submitted cork {} for {} in tx {}!",
+        cork_id, cellar_id, response.txhash
+    );
+
+    Ok(SubmitResponse {
+        tx_hash: response.txhash,
+        height: response.height as u64,
+        gas_used: response.gas_used as u64,
+        cellar_id,
+        cork_id,
+    })
+}
+
+/// Checks the client's rate limit and that the request can be scheduled, returning the contract
+/// call request and the height to schedule it at
+fn validate_schedule_request(
+    request: ScheduleCorkRequest,
+    identities: &[String],
+) -> Result<(SubmitRequest, u64), Status> {
+    let ScheduleCorkRequest {
+        request,
+        block_height,
+    } = request;
+    rate_limit::check_client(identities, 1)?;
+    let config = APP.config();
+
+    // Test mode sends contract calls to Ethereum immediately, so there is nothing to schedule with
+    if config.test_mode.enabled {
+        return Err(Status::new(
+            Code::FailedPrecondition,
+            "scheduled corks are not supported in test mode",
+        ));
+    }
+    let request = match request {
+        Some(r) => r,
+        None => {
+            return Err(Status::new(
+                Code::InvalidArgument,
+                "empty contract call request",
+            ))
+        }
+    };
+    if block_height == 0 {
+        return Err(Status::new(
+            Code::InvalidArgument,
+            "block height must be greater than zero",
+        ));
+    }
+    // Scheduled corks aren't journaled, so a key couldn't be honored
+    if !request.idempotency_key.is_empty() {
+        return Err(Status::new(
+            Code::InvalidArgument,
+            "idempotency keys are only supported by Submit",
+        ));
+    }
+
+    Ok((request, block_height))
+}
+
+/// Builds and sends the scheduled cork for a request for an approved cellar
+async fn schedule_request(
+    request: SubmitRequest,
+    block_height: u64,
+    identities: &[String],
+) -> Result<ScheduleCorkResponse, Status> {
+    let cellar_id = request.cellar_id.clone();
+    let cork = match build_cork(request).await {
+        Ok(c) => c,
+        Err(err) => {
+            warn!("failed to build cork for cellar {}: {}", cellar_id, err);
+            return Err(Status::new(Code::InvalidArgument, err.to_string()));
+        }
+    };
+    debug!("scheduled cork: {:?}", cork);
+    let cork_id = check_cork(&cork, identities).await?;
+
+    let response = match schedule_cork(
+        cork.target_contract_address,
+        cork.encoded_contract_call,
+        block_height,
+    )
+    .await
+    {
+        Ok(r) => r,
+        Err(err) => {
+            error!("failed to schedule cork: {}", err);
+            return Err(Status::new(
+                Code::Internal,
+                "failed to send scheduled cork to sommelier",
+            ));
+        }
+    };
+    info!(
+        "scheduled cork {} for {} at height {} in tx {}!",
+        cork_id, cellar_id, block_height, response.txhash
+    );
+
+    Ok(ScheduleCorkResponse {
+        tx_hash: response.txhash,
+        height: response.height as u64,
+        gas_used: response.gas_used as u64,
+        cellar_id,
+        cork_id,
+        block_height,
+    })
+}
+
+/// Runs the checks every cork passes before it's sent, whichever RPC it came from: the client's
+/// authorization, the cellar's rate limit and, when enabled, a preflight simulation of the call.
+/// Returns the cork's ID.
+async fn check_cork(cork: &Cork, identities: &[String]) -> Result<String, Status> {
+    authorize(identities, cork)?;
+    rate_limit::check_cellar(cache::cellar_key(&cork.target_contract_address))?;
+    let cork_id = match cork_id(cork) {
+        Ok(id) => id,
+        Err(err) => return Err(Status::new(Code::InvalidArgument, err.to_string())),
+    };
+
+    // Test mode sends calls from the test wallet rather than through Gravity
+    let config = APP.config();
+    if config.ethereum.preflight_calls && !config.test_mode.enabled {
+        preflight::simulate(cork).await?;
+    }
+
+    Ok(cork_id)
+}
+
+/// Checks the cork against the client's authorization policy, if one is configured
+fn authorize(identities: &[String], cork: &Cork) -> Result<(), Status> {
+    let policy = match authorization::get() {
+        Some(p) => p,
+        None => return Ok(()),
+    };
+    let functions = match REGISTRY.functions(&cork.encoded_contract_call) {
+        Ok(f) => f,
+        Err(err) => return Err(Status::new(Code::InvalidArgument, err.to_string())),
+    };
+
+    for function in functions {
+        policy
+            .authorize(identities, &cork.target_contract_address, &function)
+            .map_err(|status| {
+                info!("rejecting request: {}", status.message());
+                status
+            })?;
+    }
+
+    Ok(())
+}
+
+/// The lowercased IDs of the cellars that more than one request in a batch is for. Cellar IDs
+/// are Ethereum addresses, so they are compared case insensitively.
+fn duplicate_cellars(requests: &[SubmitRequest]) -> HashSet<String> {
+    let mut seen = HashSet::new();
+    requests
+        .iter()
+        .map(|r| r.cellar_id.to_lowercase())
+        .filter(|id| !seen.insert(id.clone()))
+        .collect()
+}
 
-        Ok(Response::new(SubmitResponse {}))
+fn batch_failure(cellar_id: String, error: String) -> SubmitBatchResult {
+    SubmitBatchResult {
+        cellar_id,
+        success: false,
+        error,
+        ..Default::default()
     }
 }
+
 // Because of Rusts handling of enums, we have no easy way to log what cellar type and function are
 // being requested before we get to the encoding step, so we pass the whole request into this method
 // and the get_encoded_call() methods so logging can happen there.
 async fn build_cork(request: SubmitRequest) -> Result<Cork, Error> {
-    cellars::validate_cellar_id(request.cellar_id.as_str())?;
-
     let address = request.cellar_id.clone();
-    let encoded_call = get_encoded_call(request)?;
+    let timer = metrics::ENCODE_LATENCY.start_timer();
+    let encoded_call = get_encoded_call(request, guardrails::get(&address));
+    timer.observe_duration();
+
+    // Calls that couldn't be encoded have no function name to label them with
+    let function = encoded_call
+        .as_ref()
+        .ok()
+        .and_then(|c| REGISTRY.decode(c).ok())
+        .map_or_else(|| "unknown".to_string(), |d| d.function);
+    metrics::CORK_REQUESTS
+        .with_label_values(&[cache::cellar_key(&address), &function])
+        .inc();
 
     Ok(Cork {
-        encoded_contract_call: encoded_call,
+        encoded_contract_call: encoded_call?,
         target_contract_address: address,
     })
 }
 
-fn get_encoded_call(request: SubmitRequest) -> Result<Vec<u8>, Error> {
+/// Computes the ID the cork module uses to identify a cork: the Keccak-256 hash of the target
+/// contract address bytes followed by the encoded contract call.
+pub fn cork_id(cork: &Cork) -> Result<String, Error> {
+    let address = cork
+        .target_contract_address
+        .parse::<H160>()
+        .map_err(|err| sp_call_error(format!("invalid ethereum address: {}", err)))?;
+    let mut bytes = address.as_bytes().to_vec();
+    bytes.extend_from_slice(&cork.encoded_contract_call);
+
+    Ok(hex::encode(keccak256(bytes)))
+}
+
+fn get_encoded_call(request: SubmitRequest, guardrails: &Guardrails) -> Result<Vec<u8>, Error> {
     if request.call_data.is_none() {
         return Err(ErrorKind::Http.context("empty contract call data").into());
     }
 
-    match request.call_data.unwrap() {
-        AaveV2Stablecoin(call) => {
-            if call.function.is_none() {
-                return Err(ErrorKind::Http.context("empty function data").into());
-            }
+    REGISTRY.encode(request.call_data.unwrap(), &request.cellar_id, guardrails)
+}
 
-            aave_v2_stablecoin::get_encoded_call(call.function.unwrap(), request.cellar_id)
+fn get_broadcast_settings() -> Result<BroadcastSettings, Error> {
+    let config = APP.config();
+    let broadcast = &config.cosmos.broadcast;
+    let mode = match broadcast.mode.as_str() {
+        "sync" => BroadcastMode::Sync,
+        "async" => BroadcastMode::Async,
+        "block" => BroadcastMode::Block,
+        mode => {
+            return Err(ErrorKind::Config
+                .context(format!("invalid broadcast mode {}", mode))
+                .into())
         }
+    };
+
+    Ok(BroadcastSettings {
+        mode,
+        timeout: Duration::from_secs(broadcast.timeout),
+        retries: broadcast.retries,
+        backoff: Duration::from_millis(broadcast.backoff_ms),
+    })
+}
+
+fn get_gas_settings() -> GasSettings {
+    let config = APP.config();
+    let (price, denom) = config.cosmos.gas_price.as_tuple();
+    GasSettings {
+        price,
+        denom,
+        adjustment: config.cosmos.cork_gas_adjustment,
+        max_fee: config.cosmos.max_fee,
     }
 }
 
 async fn send_cork(cork: Cork) -> Result<TxResponse, Error> {
-    let config = APP.config();
-    debug!("establishing grpc connection");
-    let contact = Contact::new(&config.cosmos.grpc, MESSAGE_TIMEOUT, CHAIN_PREFIX)?;
-
-    debug!("getting cosmos fee");
-    let cosmos_gas_price = config.cosmos.gas_price.as_tuple();
-    let fee = Coin {
-        amount: (cosmos_gas_price.0 as u64).into(),
-        denom: cosmos_gas_price.1,
-    };
+    let contact = connections::contact(connections::MESSAGE_TIMEOUT)?;
+    let gas = get_gas_settings();
+    let broadcast = get_broadcast_settings()?;
     somm_send::send_cork(
         &contact,
         cork,
-        config::DELEGATE_ADDRESS.to_string(),
-        &config::DELEGATE_KEY,
-        fee,
+        config::delegate_address()?.to_string(),
+        config::delegate_key()?,
+        &gas,
+        &broadcast,
+    )
+    .await
+}
+
+async fn send_corks(corks: Vec<Cork>) -> Result<TxResponse, Error> {
+    let contact = connections::contact(connections::MESSAGE_TIMEOUT)?;
+    let gas = get_gas_settings();
+    let broadcast = get_broadcast_settings()?;
+    somm_send::send_corks(
+        &contact,
+        corks,
+        config::delegate_address()?.to_string(),
+        config::delegate_key()?,
+        &gas,
+        &broadcast,
     )
     .await
-    .map_err(|e| e.into())
 }
 
 pub async fn schedule_cork(
@@ -147,16 +787,9 @@ pub async fn schedule_cork(
     encoded_call: Vec<u8>,
     height: u64,
 ) -> Result<TxResponse, Error> {
-    let config = APP.config();
-    debug!("establishing grpc connection");
-    let contact = Contact::new(&config.cosmos.grpc, MESSAGE_TIMEOUT, CHAIN_PREFIX).unwrap();
-
-    debug!("getting cosmos fee");
-    let cosmos_gas_price = config.cosmos.gas_price.as_tuple();
-    let fee = Coin {
-        amount: (cosmos_gas_price.0 as u64).into(),
-        denom: cosmos_gas_price.1,
-    };
+    let contact = connections::contact(connections::MESSAGE_TIMEOUT)?;
+    let gas = get_gas_settings();
+    let broadcast = get_broadcast_settings()?;
     let cork = Cork {
         encoded_contract_call: encoded_call,
         target_contract_address: contract.clone(),
@@ -164,11 +797,51 @@ pub async fn schedule_cork(
     somm_send::schedule_cork(
         &contact,
         cork,
-        config::DELEGATE_ADDRESS.to_string(),
-        &config::DELEGATE_KEY,
-        fee,
+        config::delegate_address()?.to_string(),
+        config::delegate_key()?,
+        &gas,
+        &broadcast,
         height,
     )
     .await
-    .map_err(|e| e.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cork_id_matches_cork_module() {
+        // setDepositLimit(1000) on the Aave V2 stablecoin cellar
+        let cork = Cork {
+            target_contract_address: "0x7bAD5DF5E11151Dc5Ee1a648800057C5c934c0d5".to_string(),
+            encoded_contract_call: hex::decode(
+                "bdc8144b00000000000000000000000000000000000000000000000000000000000003e8",
+            )
+            .unwrap(),
+        };
+
+        assert_eq!(
+            cork_id(&cork).unwrap(),
+            "dbc2d9f0ea555790d8574a28578444201566b8d25b508885f6a2ca19fa8ea36a"
+        );
+    }
+
+    #[test]
+    fn duplicate_cellars_are_found_regardless_of_case() {
+        let request = |cellar_id: &str| SubmitRequest {
+            cellar_id: cellar_id.to_string(),
+            ..Default::default()
+        };
+        let requests = vec![
+            request("0x7bAD5DF5E11151Dc5Ee1a648800057C5c934c0d5"),
+            request("0x0000000000000000000000000000000000000001"),
+            request("0x7bad5df5e11151dc5ee1a648800057c5c934c0d5"),
+        ];
+
+        let duplicates = duplicate_cellars(&requests);
+        assert_eq!(duplicates.len(), 1);
+        assert!(duplicates.contains("0x7bad5df5e11151dc5ee1a648800057c5c934c0d5"));
+        assert!(duplicate_cellars(&requests[..2]).is_empty());
+    }
 }
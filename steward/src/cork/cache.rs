@@ -0,0 +1,263 @@
+//! Cache of the cellar IDs approved by governance in the cork module. The cache is refreshed in the
+//! background so that requests don't have to wait on, or fail because of, a query to the chain.
+use crate::{
+    connections,
+    error::{Error, ErrorKind},
+    prelude::APP,
+};
+use abscissa_core::{
+    tracing::log::{debug, info, warn},
+    Application,
+};
+use lazy_static::lazy_static;
+use somm_proto::cork::QueryCellarIDsRequest;
+use std::{
+    collections::HashSet,
+    future::Future,
+    sync::RwLock,
+    time::{Duration, Instant},
+};
+use tonic::{Code, Status};
+
+/// Minimum time between refreshes triggered by requests for unknown cellar IDs, so that a client
+/// repeatedly submitting an unapproved ID can't flood the node with queries.
+const MIN_MISS_REFRESH_PERIOD: Duration = Duration::from_secs(1);
+
+/// The key that every cellar shares in test mode, see `cellar_key`
+const TEST_MODE_CELLAR_KEY: &str = "test_mode";
+
+lazy_static! {
+    static ref CACHE: CellarIdCache = CellarIdCache::default();
+}
+
+#[derive(Default)]
+struct CellarIdCache {
+    ids: RwLock<Option<CachedIds>>,
+}
+
+struct CachedIds {
+    ids: HashSet<String>,
+    updated: Instant,
+}
+
+/// Periodically refreshes the cache. Failures are logged and retried on the next period.
+pub async fn run_refresher() {
+    let period = Duration::from_secs(APP.config().cellar_id_cache.refresh_period);
+    info!("refreshing approved cellar IDs every {:?}", period);
+    loop {
+        if let Err(err) = refresh().await {
+            warn!(
+                "failed to refresh approved cellar IDs, cache age {:?}: {}",
+                age(),
+                err
+            );
+        }
+        tokio::time::sleep(period).await;
+    }
+}
+
+/// Queries the cork module for the approved cellar IDs and replaces the cached set
+pub async fn refresh() -> Result<(), Error> {
+    CACHE.replace(query_ids().await?)
+}
+
+async fn query_ids() -> Result<HashSet<String>, Error> {
+    debug!("querying approved cellar IDs");
+    let mut client = connections::cork_query_client().await?;
+
+    Ok(client
+        .query_cellar_i_ds(QueryCellarIDsRequest {})
+        .await?
+        .into_inner()
+        .cellar_ids
+        .into_iter()
+        .collect())
+}
+
+/// Time since the cache was last successfully refreshed, or None if it never has been
+pub fn age() -> Option<Duration> {
+    CACHE.age()
+}
+
+/// Checks the cellar ID against the cache, refreshing first if the cache is older than the
+/// configured staleness limit or doesn't contain the ID. In test mode there is no Sommelier chain
+/// to check cellar approval against, so every cellar ID is accepted.
+pub async fn check_cellar_approved(cellar_id: &str) -> Result<(), Status> {
+    let config = APP.config();
+    if config.test_mode.enabled {
+        return Ok(());
+    }
+
+    let max_staleness = Duration::from_secs(config.cellar_id_cache.max_staleness);
+    CACHE.check(cellar_id, max_staleness, query_ids).await
+}
+
+/// The key that metrics and rate limits use for a cellar that passed `check_cellar_approved`.
+/// Cellar IDs aren't checked in test mode, so there every cellar shares one key rather than
+/// letting clients create arbitrarily many series and buckets.
+pub fn cellar_key(cellar_id: &str) -> &str {
+    if APP.config().test_mode.enabled {
+        TEST_MODE_CELLAR_KEY
+    } else {
+        cellar_id
+    }
+}
+
+impl CellarIdCache {
+    fn age(&self) -> Option<Duration> {
+        self.ids
+            .read()
+            .ok()
+            .and_then(|c| c.as_ref().map(|c| c.updated.elapsed()))
+    }
+
+    /// Cellar IDs are Ethereum addresses, so they are compared case insensitively
+    fn contains(&self, cellar_id: &str) -> Option<bool> {
+        let cellar_id = cellar_id.to_lowercase();
+        self.ids
+            .read()
+            .ok()
+            .and_then(|c| c.as_ref().map(|c| c.ids.contains(&cellar_id)))
+    }
+
+    fn replace(&self, ids: HashSet<String>) -> Result<(), Error> {
+        let ids: HashSet<String> = ids.iter().map(|id| id.to_lowercase()).collect();
+        let mut cache = self
+            .ids
+            .write()
+            .map_err(|_| ErrorKind::Io.context("approved cellar ID cache lock poisoned"))?;
+        debug!(
+            "refreshed {} approved cellar IDs, previous cache age {:?}",
+            ids.len(),
+            cache.as_ref().map(|c| c.updated.elapsed())
+        );
+        *cache = Some(CachedIds {
+            ids,
+            updated: Instant::now(),
+        });
+
+        Ok(())
+    }
+
+    /// Checks the cellar ID, refreshing the cache with the IDs returned by `query` first if it's
+    /// stale or doesn't contain the ID
+    async fn check<F, Fut>(
+        &self,
+        cellar_id: &str,
+        max_staleness: Duration,
+        query: F,
+    ) -> Result<(), Status>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<HashSet<String>, Error>>,
+    {
+        let age = self.age();
+        let stale = age.map_or(true, |a| a > max_staleness);
+
+        let mut approved = self.contains(cellar_id);
+        let refresh_on_miss =
+            approved == Some(false) && age.map_or(true, |a| a > MIN_MISS_REFRESH_PERIOD);
+        if stale || refresh_on_miss {
+            debug!(
+                "refreshing approved cellar IDs on request, cache age {:?}",
+                age
+            );
+            match query().await.and_then(|ids| self.replace(ids)) {
+                Ok(()) => approved = self.contains(cellar_id),
+                Err(err) if stale => {
+                    warn!("approved cellar ID cache is stale, age {:?}: {}", age, err);
+                    return Err(Status::new(
+                        Code::Unavailable,
+                        "failed to query approved cellar IDs",
+                    ));
+                }
+                Err(err) => warn!(
+                    "failed to refresh approved cellar IDs, using cache aged {:?}: {}",
+                    age, err
+                ),
+            }
+        }
+
+        if approved != Some(true) {
+            info!("rejecting request for unapproved cellar {}", cellar_id);
+            return Err(Status::new(
+                Code::PermissionDenied,
+                format!("cellar ID {} not approved by governance", cellar_id),
+            ));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    const CELLAR_ID: &str = "0x7bAD5DF5E11151Dc5Ee1a648800057C5c934c0d5";
+    const MAX_STALENESS: Duration = Duration::from_secs(300);
+
+    fn cache_aged(age: Duration) -> CellarIdCache {
+        let cache = CellarIdCache::default();
+        cache
+            .replace(vec![CELLAR_ID.to_string()].into_iter().collect())
+            .unwrap();
+        cache.ids.write().unwrap().as_mut().unwrap().updated = Instant::now() - age;
+        cache
+    }
+
+    async fn query_empty() -> Result<HashSet<String>, Error> {
+        Ok(HashSet::new())
+    }
+
+    async fn query_fails() -> Result<HashSet<String>, Error> {
+        Err(ErrorKind::GrpcError.context("node unreachable").into())
+    }
+
+    async fn query_not_expected() -> Result<HashSet<String>, Error> {
+        panic!("fresh cache was refreshed")
+    }
+
+    #[test]
+    fn fresh_cache_is_used_without_querying() {
+        let cache = cache_aged(Duration::from_secs(10));
+
+        assert!(block_on(cache.check(CELLAR_ID, MAX_STALENESS, query_not_expected)).is_ok());
+        // IDs are matched regardless of case
+        assert!(block_on(cache.check(
+            &CELLAR_ID.to_lowercase(),
+            MAX_STALENESS,
+            query_not_expected
+        ))
+        .is_ok());
+    }
+
+    #[test]
+    fn expired_cache_is_refreshed() {
+        let cache = cache_aged(Duration::from_secs(600));
+        let status = block_on(cache.check(CELLAR_ID, MAX_STALENESS, query_empty)).unwrap_err();
+        assert_eq!(status.code(), Code::PermissionDenied);
+        assert!(cache.age().unwrap() < MAX_STALENESS);
+    }
+
+    #[test]
+    fn stale_cache_that_cant_be_refreshed_is_unavailable() {
+        let cache = cache_aged(Duration::from_secs(600));
+        let status = block_on(cache.check(CELLAR_ID, MAX_STALENESS, query_fails)).unwrap_err();
+        assert_eq!(status.code(), Code::Unavailable);
+
+        let empty = CellarIdCache::default();
+        let status = block_on(empty.check(CELLAR_ID, MAX_STALENESS, query_fails)).unwrap_err();
+        assert_eq!(status.code(), Code::Unavailable);
+    }
+
+    #[test]
+    fn fresh_cache_is_used_when_refresh_on_miss_fails() {
+        let cache = cache_aged(Duration::from_secs(10));
+        let other = "0x0000000000000000000000000000000000000000";
+
+        let status = block_on(cache.check(other, MAX_STALENESS, query_fails)).unwrap_err();
+        assert_eq!(status.code(), Code::PermissionDenied);
+    }
+}
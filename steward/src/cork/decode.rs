@@ -0,0 +1,82 @@
+//! Decoding of cork contract calls, so that validators can audit what the corks they see on chain
+//! would do
+use crate::{cellars::REGISTRY, error::Error};
+use steward_proto::steward::DecodedCall;
+
+/// Decodes a contract call against the ABIs of the supported cellar families. The calls wrapped
+/// by a multicall are decoded too.
+pub fn decode(encoded_call: &[u8]) -> Result<DecodedCall, Error> {
+    let decoded = REGISTRY.decode(encoded_call)?;
+    let calls = decoded
+        .wrapped_calls()?
+        .into_iter()
+        .map(decode)
+        .collect::<Result<Vec<DecodedCall>, Error>>()?;
+
+    Ok(DecodedCall {
+        cellar: decoded.cellar.to_string(),
+        selector: hex::encode(&encoded_call[..4]),
+        arguments: decoded.arguments_json().to_string(),
+        function: decoded.function,
+        signature: decoded.signature,
+        calls,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::guardrails::Guardrails;
+    use serde_json::{json, Value};
+    use steward_proto::steward::{
+        aave_v2_stablecoin::{Accrue, Function, Multicall, SetDepositLimit},
+        submit_request::CallData,
+        AaveV2Stablecoin,
+    };
+
+    #[test]
+    fn decodes_calls_wrapped_by_multicall() {
+        let call = |function| AaveV2Stablecoin {
+            function: Some(function),
+        };
+        let call_data = CallData::AaveV2Stablecoin(call(Function::Multicall(Multicall {
+            calls: vec![
+                call(Function::Accrue(Accrue {})),
+                call(Function::SetDepositLimit(SetDepositLimit {
+                    limit: "1000".to_string(),
+                })),
+            ],
+        })));
+        let encoded_call = REGISTRY
+            .encode(
+                call_data,
+                "0x0000000000000000000000000000000000000000",
+                &Guardrails::default(),
+            )
+            .unwrap();
+
+        let decoded = decode(&encoded_call).unwrap();
+        assert_eq!(decoded.cellar, "aave_v2_stablecoin");
+        assert_eq!(decoded.function, "multicall");
+        assert_eq!(decoded.selector, hex::encode(&encoded_call[..4]));
+
+        let calls: Vec<(&str, Value)> = decoded
+            .calls
+            .iter()
+            .map(|c| {
+                (
+                    c.function.as_str(),
+                    serde_json::from_str(&c.arguments).unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            calls,
+            vec![
+                ("accrue", json!({})),
+                ("setDepositLimit", json!({ "newLimit": "1000" })),
+            ]
+        );
+        assert!(decoded.calls.iter().all(|c| c.calls.is_empty()));
+    }
+}
@@ -0,0 +1,90 @@
+//! Queries of the submission journal
+use crate::{
+    cellars::REGISTRY,
+    error::Error,
+    journal::{HistoryFilter, Journal, Submission},
+};
+use abscissa_core::tracing::log::debug;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use steward_proto::steward::{
+    GetSubmissionHistoryRequest, GetSubmissionHistoryResponse, SubmissionRecord,
+};
+
+const DEFAULT_LIMIT: usize = 100;
+
+/// Lists the journaled submissions matching the request. When `clients` is set, only submissions
+/// made by a client with one of those identities are listed.
+pub fn get_submission_history(
+    journal: &Journal,
+    request: GetSubmissionHistoryRequest,
+    clients: Option<Vec<String>>,
+) -> Result<GetSubmissionHistoryResponse, Error> {
+    let filter = HistoryFilter {
+        clients,
+        cellar_id: non_empty(request.cellar_id),
+        start: timestamp(request.start_time),
+        end: timestamp(request.end_time),
+        status: match non_empty(request.status) {
+            Some(s) => Some(s.parse()?),
+            None => None,
+        },
+        limit: match request.limit {
+            0 => DEFAULT_LIMIT,
+            l => l as usize,
+        },
+    };
+    debug!("querying submission history: {:?}", filter);
+
+    let submissions = journal
+        .history(&filter)?
+        .into_iter()
+        .map(to_record)
+        .collect();
+
+    Ok(GetSubmissionHistoryResponse { submissions })
+}
+
+/// Decodes the submitted contract call so that operators can read what was sent. Requests that
+/// failed before being encoded have no function or arguments.
+fn to_record(submission: Submission) -> SubmissionRecord {
+    let decoded = hex::decode(&submission.encoded_call)
+        .ok()
+        .filter(|c| !c.is_empty())
+        .and_then(|c| REGISTRY.decode(&c).ok());
+    let (function, arguments) = match decoded {
+        Some(d) => (d.function.clone(), d.arguments_json().to_string()),
+        None => (String::new(), String::new()),
+    };
+
+    SubmissionRecord {
+        id: submission.id,
+        function,
+        arguments,
+        status: submission.status.as_str().to_string(),
+        created_at: submission.created_at().timestamp(),
+        updated_at: submission.updated_at().timestamp(),
+        cellar_id: submission.cellar_id,
+        cork_id: submission.cork_id,
+        tx_hash: submission.tx_hash,
+        height: submission.height,
+        error: submission.error,
+        idempotency_key: submission.idempotency_key,
+    }
+}
+
+fn non_empty(s: String) -> Option<String> {
+    if s.is_empty() {
+        None
+    } else {
+        Some(s)
+    }
+}
+
+/// Zero means the bound is unset
+fn timestamp(seconds: i64) -> Option<DateTime<Utc>> {
+    if seconds == 0 {
+        return None;
+    }
+
+    NaiveDateTime::from_timestamp_opt(seconds, 0).map(|t| DateTime::from_utc(t, Utc))
+}
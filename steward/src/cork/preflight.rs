@@ -0,0 +1,191 @@
+//! Simulation of corks against the cellar before they're submitted. Gravity is the only sender
+//! cellars accept calls from, so a call that reverts when simulated from it would fail once the
+//! cork is relayed, after validators have already voted for it. A multicall is simulated as one
+//! call, so its wrapped calls run in order against the state the earlier ones leave behind.
+use crate::{
+    cellars::REGISTRY,
+    error::{Error, ErrorKind},
+    prelude::APP,
+    utils::{get_eth_provider, sp_call_error},
+};
+use abscissa_core::{
+    tracing::log::{debug, error, info, warn},
+    Application,
+};
+use ethers::{
+    prelude::*,
+    providers::{HttpClientError, ProviderError},
+    types::transaction::eip2718::TypedTransaction,
+};
+use somm_proto::cork::Cork;
+use tonic::{Code, Status};
+
+/// Runs the cork's contract call with `eth_call` from the Gravity contract, rejecting it with the
+/// decoded revert reason if it reverts
+pub async fn simulate(cork: &Cork) -> Result<(), Status> {
+    let tx = match transaction(cork, &APP.config().gravity.contract) {
+        Ok(tx) => tx,
+        // A bad Gravity address is the server's problem, not the client's
+        Err(err) if *err.kind() == ErrorKind::Config => {
+            error!("failed to build preflight call: {}", err);
+            return Err(Status::new(
+                Code::Internal,
+                "failed to simulate contract call",
+            ));
+        }
+        Err(err) => return Err(Status::new(Code::InvalidArgument, err.to_string())),
+    };
+    let provider = get_eth_provider().await.map_err(|err| {
+        warn!("failed to connect to ethereum for preflight: {}", err);
+        Status::new(Code::Unavailable, "failed to simulate contract call")
+    })?;
+
+    let err = match provider.call(&tx, None).await {
+        Ok(_) => {
+            debug!(
+                "preflight call to {} succeeded",
+                cork.target_contract_address
+            );
+            return Ok(());
+        }
+        Err(err) => err,
+    };
+
+    match revert_data(&err) {
+        Some(data) => {
+            let reason = REGISTRY.decode_revert(&data);
+            info!(
+                "rejecting cork for {}, contract call would revert: {}",
+                cork.target_contract_address, reason
+            );
+            Err(Status::new(
+                Code::FailedPrecondition,
+                format!("contract call would revert: {}", reason),
+            ))
+        }
+        None => {
+            warn!("failed to simulate contract call: {}", err);
+            Err(Status::new(
+                Code::Unavailable,
+                "failed to simulate contract call",
+            ))
+        }
+    }
+}
+
+/// Builds the `eth_call` of the cork's contract call from the Gravity contract at `gravity`
+fn transaction(cork: &Cork, gravity: &str) -> Result<TypedTransaction, Error> {
+    let gravity = gravity.parse::<H160>().map_err(|err| {
+        ErrorKind::Config.context(format!("invalid gravity contract address: {}", err))
+    })?;
+    let cellar = cork
+        .target_contract_address
+        .parse::<H160>()
+        .map_err(|err| sp_call_error(format!("invalid ethereum address: {}", err)))?;
+
+    Ok(TransactionRequest::new()
+        .from(gravity)
+        .to(cellar)
+        .data(cork.encoded_contract_call.clone())
+        .into())
+}
+
+/// Extracts the revert data from an `eth_call` error. Nodes return it as a hex string in the
+/// JSON-RPC error's `data` field. Returns None if the call failed for another reason, such as the
+/// node being unreachable. Reverts without data return empty data.
+fn revert_data(err: &ProviderError) -> Option<Vec<u8>> {
+    let err = match err {
+        ProviderError::JsonRpcClientError(e) => e.downcast_ref::<HttpClientError>()?,
+        _ => return None,
+    };
+    let err = match err {
+        HttpClientError::JsonRpcError(e) => e,
+        _ => return None,
+    };
+
+    if let Some(data) = err.data.as_ref().and_then(|d| d.as_str()) {
+        if let Ok(data) = hex::decode(data.trim_start_matches("0x")) {
+            return Some(data);
+        }
+    }
+    if err.message.contains("revert") {
+        return Some(Vec::new());
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const GRAVITY: &str = "0x69592e6f9d21989a043646fE8225da2600e5A0f7";
+    const CELLAR_ID: &str = "0x7bAD5DF5E11151Dc5Ee1a648800057C5c934c0d5";
+
+    fn rpc_error(error: serde_json::Value) -> ProviderError {
+        let err = HttpClientError::JsonRpcError(serde_json::from_value(error).unwrap());
+        ProviderError::JsonRpcClientError(Box::new(err))
+    }
+
+    fn cork(target_contract_address: &str) -> Cork {
+        Cork {
+            target_contract_address: target_contract_address.to_string(),
+            encoded_contract_call: vec![0xbd, 0xc8, 0x14, 0x4b],
+        }
+    }
+
+    #[test]
+    fn revert_data_is_decoded_from_hex() {
+        let err = rpc_error(json!({
+            "code": 3,
+            "message": "execution reverted",
+            "data": "0x08c379a0",
+        }));
+
+        assert_eq!(revert_data(&err), Some(vec![0x08, 0xc3, 0x79, 0xa0]));
+    }
+
+    #[test]
+    fn revert_without_data_has_empty_data() {
+        let err = rpc_error(json!({
+            "code": -32000,
+            "message": "execution reverted",
+        }));
+
+        assert_eq!(revert_data(&err), Some(Vec::new()));
+    }
+
+    #[test]
+    fn other_errors_are_not_reverts() {
+        let err = rpc_error(json!({
+            "code": -32000,
+            "message": "header not found",
+        }));
+        assert_eq!(revert_data(&err), None);
+
+        let err = ProviderError::CustomError("connection refused".to_string());
+        assert_eq!(revert_data(&err), None);
+    }
+
+    #[test]
+    fn transaction_is_sent_from_gravity_to_the_cellar() {
+        let tx = transaction(&cork(CELLAR_ID), GRAVITY).unwrap();
+
+        assert_eq!(tx.from(), Some(&GRAVITY.parse::<H160>().unwrap()));
+        assert_eq!(
+            tx.to(),
+            Some(&NameOrAddress::Address(CELLAR_ID.parse().unwrap()))
+        );
+        assert_eq!(tx.data().unwrap().as_ref(), &[0xbd, 0xc8, 0x14, 0x4b]);
+    }
+
+    #[test]
+    fn bad_gravity_address_is_a_config_error_unlike_a_bad_cellar_id() {
+        let err = transaction(&cork(CELLAR_ID), "not an address").unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::Config);
+
+        let err = transaction(&cork("not an address"), GRAVITY).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::SPCallError);
+    }
+}
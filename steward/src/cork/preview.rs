@@ -0,0 +1,108 @@
+//! Dry runs of contract call requests, so that strategists can see the cork Steward would submit
+//! for a request without anything being sent
+use super::{authorize, cache, cork_id, get_encoded_call};
+use crate::{
+    cellars::REGISTRY,
+    guardrails::{self, Guardrails},
+};
+use abscissa_core::tracing::log::debug;
+use somm_proto::cork::Cork;
+use steward_proto::steward::{self, PreviewResponse, SubmitRequest};
+use tonic::{Code, Status};
+
+/// Runs the same validation and encoding as a submission, including the approval, authorization
+/// and guardrail checks, and returns the resulting cork
+pub async fn preview(
+    request: SubmitRequest,
+    identities: &[String],
+) -> Result<PreviewResponse, Status> {
+    cache::check_cellar_approved(&request.cellar_id).await?;
+
+    let guardrails = guardrails::get(&request.cellar_id);
+    let cork = build_cork(request, guardrails)?;
+    authorize(identities, &cork)?;
+
+    describe(cork)
+}
+
+fn build_cork(request: SubmitRequest, guardrails: &Guardrails) -> Result<Cork, Status> {
+    let cork = Cork {
+        target_contract_address: request.cellar_id.clone(),
+        encoded_contract_call: get_encoded_call(request, guardrails)
+            .map_err(|err| Status::new(Code::InvalidArgument, err.to_string()))?,
+    };
+    debug!("previewed cork: {:?}", cork);
+
+    Ok(cork)
+}
+
+fn describe(cork: Cork) -> Result<PreviewResponse, Status> {
+    let decoded = REGISTRY
+        .decode(&cork.encoded_contract_call)
+        .map_err(|err| Status::new(Code::Internal, err.to_string()))?;
+    let cork_id =
+        cork_id(&cork).map_err(|err| Status::new(Code::InvalidArgument, err.to_string()))?;
+
+    Ok(PreviewResponse {
+        cork_id,
+        encoded_call: hex::encode(&cork.encoded_contract_call),
+        selector: hex::encode(&cork.encoded_contract_call[..4]),
+        arguments: decoded.arguments_json().to_string(),
+        function: decoded.function,
+        signature: decoded.signature,
+        cork: Some(steward::Cork {
+            encoded_contract_call: cork.encoded_contract_call,
+            target_contract_address: cork.target_contract_address,
+        }),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use steward_proto::steward::{
+        aave_v2_stablecoin::{Function, SetDepositLimit},
+        submit_request::CallData,
+        AaveV2Stablecoin,
+    };
+
+    const CELLAR_ID: &str = "0x7bAD5DF5E11151Dc5Ee1a648800057C5c934c0d5";
+
+    fn set_deposit_limit(limit: &str) -> SubmitRequest {
+        SubmitRequest {
+            cellar_id: CELLAR_ID.to_string(),
+            call_data: Some(CallData::AaveV2Stablecoin(AaveV2Stablecoin {
+                function: Some(Function::SetDepositLimit(SetDepositLimit {
+                    limit: limit.to_string(),
+                })),
+            })),
+            idempotency_key: String::new(),
+        }
+    }
+
+    #[test]
+    fn previews_cork_for_valid_call() {
+        let cork = build_cork(set_deposit_limit("1000"), &Guardrails::default()).unwrap();
+        let preview = describe(cork).unwrap();
+
+        assert_eq!(preview.function, "setDepositLimit");
+        assert_eq!(preview.selector, "bdc8144b");
+        assert_eq!(
+            preview.cork_id,
+            "dbc2d9f0ea555790d8574a28578444201566b8d25b508885f6a2ca19fa8ea36a"
+        );
+        assert_eq!(preview.cork.unwrap().target_contract_address, CELLAR_ID);
+    }
+
+    #[test]
+    fn rejects_call_outside_guardrails() {
+        let guardrails = Guardrails {
+            max_deposit_limit: Some(999.into()),
+            ..Default::default()
+        };
+        let status = build_cork(set_deposit_limit("1000"), &guardrails).unwrap_err();
+
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert!(status.message().contains("guardrail"));
+    }
+}
@@ -0,0 +1,222 @@
+//! Reports the progress of a cork through the cork module's vote and the Gravity bridge
+use crate::{
+    connections,
+    error::{Error, ErrorKind},
+    prelude::APP,
+    utils::{get_eth_provider, sp_call_error},
+};
+use abscissa_core::{tracing::log::debug, Application};
+use ethers::prelude::*;
+use gravity_bridge::{
+    gravity_abi::gravity::Gravity,
+    gravity_proto::{
+        cosmos_sdk_proto::cosmos::staking::v1beta1::{
+            query_client::QueryClient as StakingQueryClient, QueryPoolRequest,
+            QueryValidatorRequest,
+        },
+        gravity::{query_client::QueryClient as GravityQueryClient, ContractCallTxsRequest},
+    },
+};
+use somm_proto::cork::{Cork, QueryScheduledCorksRequest, QuerySubmittedCorksRequest};
+use std::sync::Arc;
+use steward_proto::steward::GetCorkStatusResponse;
+use tonic::transport::Channel;
+
+/// Tokens per unit of consensus power. This is the Cosmos SDK's default power reduction.
+const POWER_REDUCTION: u128 = 1_000_000;
+
+/// Queries the status of the cork with the given ID, which must already be normalized by
+/// `normalize_cork_id`
+pub async fn get_cork_status(cork_id: &str) -> Result<GetCorkStatusResponse, Error> {
+    let cork_id = cork_id.to_string();
+
+    debug!("querying cork module for votes on cork {}", cork_id);
+    let mut cork_client = connections::cork_query_client().await?;
+    let submitted_votes = cork_client
+        .query_submitted_corks(QuerySubmittedCorksRequest {})
+        .await?
+        .into_inner()
+        .corks
+        .iter()
+        .filter(|c| matches_cork_id(c, &cork_id))
+        .count() as u64;
+
+    // Scheduled corks are the only votes the cork module attributes to a validator
+    let scheduled = cork_client
+        .query_scheduled_corks(QueryScheduledCorksRequest {})
+        .await?
+        .into_inner()
+        .corks;
+    let scheduled_height = scheduled
+        .iter()
+        .find(|sc| {
+            sc.cork
+                .as_ref()
+                .map_or(false, |c| matches_cork_id(c, &cork_id))
+        })
+        .map_or(0, |sc| sc.block_height);
+    let voters: Vec<String> = scheduled
+        .into_iter()
+        .filter(|sc| {
+            sc.block_height == scheduled_height
+                && sc
+                    .cork
+                    .as_ref()
+                    .map_or(false, |c| matches_cork_id(c, &cork_id))
+        })
+        .map(|sc| sc.validator)
+        .collect();
+
+    let mut gravity_client = connections::gravity_query_client().await?;
+    let pending_nonce = get_contract_call_nonce(&mut gravity_client, &cork_id).await?;
+
+    debug!("querying staking module for vote power");
+    let mut staking_client = connections::staking_query_client().await?;
+    let mut vote_power = 0;
+    for validator in voters {
+        vote_power += get_validator_power(&mut staking_client, validator).await?;
+    }
+    let total_power = match staking_client
+        .pool(QueryPoolRequest {})
+        .await?
+        .into_inner()
+        .pool
+    {
+        Some(pool) => tokens_to_power(&pool.bonded_tokens)?,
+        None => {
+            return Err(ErrorKind::GrpcError
+                .context("empty staking pool response")
+                .into())
+        }
+    };
+
+    let votes_pending = submitted_votes > 0 || scheduled_height > 0;
+    let executed = is_executed(
+        pending_nonce,
+        votes_pending,
+        get_executed_nonce(&cork_id).await?,
+    );
+
+    Ok(GetCorkStatusResponse {
+        cork_id,
+        submitted_votes,
+        scheduled_height,
+        vote_power,
+        total_power,
+        executed,
+    })
+}
+
+/// Whether the cork's contract call has run on Ethereum. Identical calls share an invalidation
+/// scope, so the Gravity contract's nonce for the scope is only compared against the nonce of the
+/// cork's own contract call. While the cork is waiting to be relayed, Gravity holds its contract
+/// call and nonce; once the call has executed Gravity prunes it, so a cork with no pending contract
+/// call and no pending votes has executed if anything has under its scope.
+fn is_executed(pending_nonce: Option<u64>, votes_pending: bool, executed_nonce: U256) -> bool {
+    match pending_nonce {
+        Some(nonce) => executed_nonce >= U256::from(nonce),
+        None => !votes_pending && !executed_nonce.is_zero(),
+    }
+}
+
+/// Looks up the invalidation nonce Gravity assigned to the cork's contract call, if the cork module
+/// has approved the cork and the call is waiting to be relayed. The cork module uses the cork ID as
+/// the invalidation scope.
+async fn get_contract_call_nonce(
+    client: &mut GravityQueryClient<Channel>,
+    cork_id: &str,
+) -> Result<Option<u64>, Error> {
+    let scope = hex::decode(cork_id).map_err(|err| sp_call_error(err.to_string()))?;
+    let calls = client
+        .contract_call_txs(ContractCallTxsRequest { pagination: None })
+        .await?
+        .into_inner()
+        .calls;
+
+    Ok(calls
+        .iter()
+        .filter(|c| c.invalidation_scope == scope)
+        .map(|c| c.invalidation_nonce)
+        .max())
+}
+
+/// Reads the Gravity contract's invalidation mapping, which records the nonce of the last logic
+/// call executed for a given invalidation scope
+async fn get_executed_nonce(cork_id: &str) -> Result<U256, Error> {
+    let config = APP.config();
+    let gravity_address = config.gravity.contract.parse::<H160>().map_err(|err| {
+        ErrorKind::Config.context(format!("invalid gravity contract address: {}", err))
+    })?;
+    let mut scope = [0u8; 32];
+    scope.copy_from_slice(&hex::decode(cork_id).map_err(|err| sp_call_error(err.to_string()))?);
+
+    let provider = get_eth_provider().await?;
+    let gravity = Gravity::new(gravity_address, Arc::new(provider));
+    let nonce = gravity.state_invalidation_mapping(scope).call().await?;
+    debug!("invalidation nonce for scope {} is {}", cork_id, nonce);
+
+    Ok(nonce)
+}
+
+async fn get_validator_power(
+    client: &mut StakingQueryClient<Channel>,
+    validator_addr: String,
+) -> Result<u64, Error> {
+    let response = client
+        .validator(QueryValidatorRequest {
+            validator_addr: validator_addr.clone(),
+        })
+        .await?
+        .into_inner();
+    match response.validator {
+        Some(v) => tokens_to_power(&v.tokens),
+        None => Err(ErrorKind::GrpcError
+            .context(format!("validator {} not found", validator_addr))
+            .into()),
+    }
+}
+
+fn tokens_to_power(tokens: &str) -> Result<u64, Error> {
+    let tokens = tokens
+        .parse::<u128>()
+        .map_err(|err| ErrorKind::GrpcError.context(format!("invalid token amount: {}", err)))?;
+
+    Ok((tokens / POWER_REDUCTION) as u64)
+}
+
+fn matches_cork_id(cork: &Cork, cork_id: &str) -> bool {
+    super::cork_id(cork).map_or(false, |id| id == cork_id)
+}
+
+/// Cork IDs are accepted with or without a 0x prefix and in either case
+pub fn normalize_cork_id(cork_id: &str) -> Result<String, Error> {
+    let id = cork_id.trim_start_matches("0x").to_lowercase();
+    if id.len() != 64 || hex::decode(&id).is_err() {
+        return Err(sp_call_error(format!(
+            "cork ID must be a 32 byte hex string: {}",
+            cork_id
+        )));
+    }
+
+    Ok(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_cork_is_not_executed_by_an_identical_earlier_call() {
+        // An identical call already ran at nonce 3, this cork's call has nonce 7
+        assert!(!is_executed(Some(7), false, U256::from(3)));
+        assert!(is_executed(Some(7), false, U256::from(7)));
+        // Still being voted on
+        assert!(!is_executed(None, true, U256::from(3)));
+    }
+
+    #[test]
+    fn relayed_cork_is_executed() {
+        assert!(is_executed(None, false, U256::from(7)));
+        assert!(!is_executed(None, false, U256::zero()));
+    }
+}
@@ -13,7 +13,7 @@ use std::{
     ops::Deref,
 };
 use thiserror::Error;
-use tonic::transport::Error as TonicError;
+use tonic::{transport::Error as TonicError, Status};
 
 /// Kinds of errors
 #[derive(Copy, Clone, Debug, Eq, Error, PartialEq)]
@@ -48,6 +48,9 @@ pub enum ErrorKind {
     /// Strategy Provider call error
     #[error("SP call error")]
     SPCallError,
+    /// TLS configuration or handshake error
+    #[error("tls error")]
+    Tls,
     /// Client error
     #[error("client error")]
     ClientError,
@@ -164,9 +167,28 @@ impl From<ProviderError> for Error {
     }
 }
 
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        ErrorKind::Io.context(err).into()
+    }
+}
+
+impl From<sled::Error> for Error {
+    fn from(err: sled::Error) -> Self {
+        ErrorKind::Io.context(err).into()
+    }
+}
+
 impl From<TonicError> for Error {
     fn from(err: TonicError) -> Self {
         let err: BoxError = err.into();
         ErrorKind::GrpcError.context(err).into()
     }
 }
+
+impl From<Status> for Error {
+    fn from(err: Status) -> Self {
+        let err: BoxError = err.into();
+        ErrorKind::GrpcError.context(err).into()
+    }
+}
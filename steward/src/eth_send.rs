@@ -0,0 +1,86 @@
+//! Sends cellar function calls directly to Ethereum as a single signer. This is used in test mode
+//! in place of submitting corks to the Sommelier chain.
+use crate::{
+    error::{Error, ErrorKind},
+    gas::CellarGas,
+    prelude::APP,
+    utils::{get_eth_provider, sp_call_error},
+};
+use abscissa_core::{tracing::log::debug, Application};
+use ethers::prelude::*;
+use gravity_bridge::gravity_utils::ethereum::downcast_to_u64;
+use lazy_static::lazy_static;
+
+lazy_static! {
+    static ref WALLET: Result<LocalWallet, String> = {
+        let config = APP.config();
+        config
+            .try_load_ethers_wallet(config.test_mode.ethereum_key.clone())
+            .map_err(|err| err.to_string())
+    };
+}
+
+/// Loads the test mode signing key from the keystore. Called at startup so that a missing or bad
+/// key fails there rather than on the first request.
+pub fn load_wallet() -> Result<&'static LocalWallet, Error> {
+    WALLET.as_ref().map_err(|err| {
+        ErrorKind::KeysError
+            .context(format!("failed to load test mode ethereum key: {}", err))
+            .into()
+    })
+}
+
+pub async fn send_contract_call(
+    cellar_id: &str,
+    encoded_call: Vec<u8>,
+) -> Result<TransactionReceipt, Error> {
+    let cellar = cellar_id
+        .parse::<H160>()
+        .map_err(|err| sp_call_error(format!("invalid ethereum address: {}", err)))?;
+
+    let provider = get_eth_provider().await?;
+    let chain_id = provider.get_chainid().await?;
+    let chain_id = match downcast_to_u64(chain_id) {
+        Some(id) => id,
+        None => {
+            return Err(ErrorKind::ClientError
+                .context(format!("Chain ID is larger than u64 max: {}", chain_id))
+                .into())
+        }
+    };
+    let wallet = load_wallet()?.clone().with_chain_id(chain_id);
+    let client = SignerMiddleware::new(provider, wallet);
+
+    let gas_price = CellarGas::get_gas_price().await?;
+    let gas_price = CellarGas::apply_gas_multiplier(gas_price)?;
+    debug!("sending contract call with gas price {}", gas_price);
+
+    let tx = TransactionRequest::new()
+        .to(cellar)
+        .data(encoded_call)
+        .gas_price(gas_price);
+    let pending = client
+        .send_transaction(tx, None)
+        .await
+        .map_err(|err| ErrorKind::ContractError.context(err))?;
+    debug!("contract call sent to {} in tx {:?}", cellar_id, *pending);
+
+    let receipt = match pending.await? {
+        Some(r) => r,
+        None => {
+            return Err(ErrorKind::ContractError
+                .context("contract call tx was dropped from the mempool")
+                .into())
+        }
+    };
+    if receipt.status == Some(0u64.into()) {
+        return Err(ErrorKind::ContractError
+            .context(format!(
+                "contract call tx {:?} reverted",
+                receipt.transaction_hash
+            ))
+            .into());
+    }
+
+    Ok(receipt)
+}
@@ -0,0 +1,263 @@
+//! Validator-side limits on the arguments of cellar calls. Steward signs any well formed call to an
+//! approved cellar, so these let a validator refuse calls it considers unsafe, such as an absurd
+//! deposit limit or a rebalance without slippage protection, before they are signed.
+use crate::{
+    cellars::REGISTRY,
+    config::GuardrailsSection,
+    error::{Error, ErrorKind},
+    prelude::APP,
+    utils::{sp_call_error, string_to_u256},
+};
+use abscissa_core::{tracing::log::info, Application};
+use ethers::prelude::{H160, U256};
+use lazy_static::lazy_static;
+use once_cell::sync::OnceCell;
+use std::collections::HashMap;
+
+/// Guardrails by lowercase cellar ID
+static GUARDRAILS: OnceCell<HashMap<String, Guardrails>> = OnceCell::new();
+
+lazy_static! {
+    static ref NONE: Guardrails = Guardrails::default();
+}
+
+/// Parses the configured guardrails. Called at startup so that invalid guardrails fail there
+/// rather than on the first request.
+pub fn load() -> Result<(), Error> {
+    GUARDRAILS.get_or_try_init(|| from_sections(&APP.config().guardrails))?;
+
+    Ok(())
+}
+
+/// Returns the guardrails configured for the cellar. Cellars without any, or any call before the
+/// guardrails are loaded, allow every call.
+pub fn get(cellar_id: &str) -> &'static Guardrails {
+    GUARDRAILS
+        .get()
+        .and_then(|g| g.get(&cellar_id.to_lowercase()))
+        .unwrap_or(&NONE)
+}
+
+/// Parses the guardrails of each cellar, keyed by lowercase cellar ID. Each cellar may only have one
+/// section, since a second one would otherwise silently replace the first.
+fn from_sections(sections: &[GuardrailsSection]) -> Result<HashMap<String, Guardrails>, Error> {
+    let mut guardrails = HashMap::new();
+    for section in sections {
+        info!("loading guardrails for cellar {}", section.cellar_id);
+        let cellar_id = section.cellar_id.to_lowercase();
+        if guardrails.contains_key(&cellar_id) {
+            return Err(ErrorKind::Config
+                .context(format!(
+                    "more than one guardrails section for cellar {}",
+                    section.cellar_id
+                ))
+                .into());
+        }
+        guardrails.insert(cellar_id, Guardrails::from_config(section)?);
+    }
+
+    Ok(guardrails)
+}
+
+/// Parsed from a [`GuardrailsSection`]. Unset limits aren't checked.
+#[derive(Clone, Debug, Default)]
+pub struct Guardrails {
+    /// Function names as they appear in the cellar ABI
+    pub disabled_functions: Vec<String>,
+    pub max_deposit_limit: Option<U256>,
+    pub max_liquidity_limit: Option<U256>,
+    /// Floor on the `min_assets_out` slippage parameter of swaps
+    pub min_assets_out: Option<U256>,
+    /// Tokens and pools a rebalance route may pass through. Empty allows any.
+    pub allowed_route_addresses: Vec<H160>,
+    /// Curve swap types a rebalance may use. Empty allows any.
+    pub allowed_swap_types: Vec<u64>,
+}
+
+impl Guardrails {
+    pub fn from_config(section: &GuardrailsSection) -> Result<Self, Error> {
+        let amount = |value: &Option<String>| value.clone().map(string_to_u256).transpose();
+        let allowed_route_addresses = section
+            .allowed_route_addresses
+            .iter()
+            .map(|a| {
+                a.parse::<H160>().map_err(|err| {
+                    ErrorKind::Config
+                        .context(format!("invalid guardrail address {}: {}", a, err))
+                        .into()
+                })
+            })
+            .collect::<Result<Vec<H160>, Error>>()?;
+
+        // A misspelled name would otherwise leave the function enabled
+        for function in &section.disabled_functions {
+            if !REGISTRY
+                .adapters()
+                .any(|adapter| adapter.abi().function(function).is_ok())
+            {
+                return Err(ErrorKind::Config
+                    .context(format!(
+                        "disabled function {} is not in any cellar ABI",
+                        function
+                    ))
+                    .into());
+            }
+        }
+
+        Ok(Guardrails {
+            disabled_functions: section.disabled_functions.clone(),
+            max_deposit_limit: amount(&section.max_deposit_limit)?,
+            max_liquidity_limit: amount(&section.max_liquidity_limit)?,
+            min_assets_out: amount(&section.min_assets_out)?,
+            allowed_route_addresses,
+            allowed_swap_types: section.allowed_swap_types.clone(),
+        })
+    }
+
+    pub fn check_enabled(&self, function: &str) -> Result<(), Error> {
+        if self.disabled_functions.iter().any(|f| f == function) {
+            return Err(guardrail_error(format!(
+                "{} is disabled for this cellar",
+                function
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn check_deposit_limit(&self, limit: U256) -> Result<(), Error> {
+        check_max("deposit limit", limit, self.max_deposit_limit)
+    }
+
+    pub fn check_liquidity_limit(&self, limit: U256) -> Result<(), Error> {
+        check_max("liquidity limit", limit, self.max_liquidity_limit)
+    }
+
+    pub fn check_min_assets_out(&self, min_assets_out: U256) -> Result<(), Error> {
+        match self.min_assets_out {
+            Some(floor) if min_assets_out < floor => Err(guardrail_error(format!(
+                "min_assets_out {} is below the floor of {}",
+                min_assets_out, floor
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    /// The zero address is always allowed since clients pad routes with it
+    pub fn check_route(&self, route: &[H160]) -> Result<(), Error> {
+        if self.allowed_route_addresses.is_empty() {
+            return Ok(());
+        }
+
+        let disallowed: Vec<String> = route
+            .iter()
+            .filter(|a| !a.is_zero() && !self.allowed_route_addresses.contains(*a))
+            .map(|a| format!("{:?}", a))
+            .collect();
+        if !disallowed.is_empty() {
+            return Err(guardrail_error(format!(
+                "route contains addresses that aren't allowed: {}",
+                disallowed.join(", ")
+            )));
+        }
+
+        Ok(())
+    }
+
+    pub fn check_swap_types(&self, swap_types: &[u64]) -> Result<(), Error> {
+        if self.allowed_swap_types.is_empty() {
+            return Ok(());
+        }
+
+        match swap_types
+            .iter()
+            .find(|t| !self.allowed_swap_types.contains(*t))
+        {
+            Some(t) => Err(guardrail_error(format!("swap type {} isn't allowed", t))),
+            None => Ok(()),
+        }
+    }
+}
+
+fn check_max(name: &str, value: U256, max: Option<U256>) -> Result<(), Error> {
+    match max {
+        Some(max) if value > max => Err(guardrail_error(format!(
+            "{} {} exceeds the maximum of {}",
+            name, value, max
+        ))),
+        _ => Ok(()),
+    }
+}
+
+fn guardrail_error(message: String) -> Error {
+    sp_call_error(format!("rejected by guardrail: {}", message))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn section() -> GuardrailsSection {
+        GuardrailsSection {
+            cellar_id: "0x0000000000000000000000000000000000000000".to_string(),
+            disabled_functions: vec!["setAccrualPeriod".to_string()],
+            max_deposit_limit: Some("1000".to_string()),
+            max_liquidity_limit: None,
+            min_assets_out: Some("10".to_string()),
+            allowed_route_addresses: vec!["0x1111111111111111111111111111111111111111".to_string()],
+            allowed_swap_types: vec![1, 2],
+        }
+    }
+
+    fn guardrails() -> Guardrails {
+        Guardrails::from_config(&section()).unwrap()
+    }
+
+    #[test]
+    fn unknown_disabled_functions_are_rejected() {
+        let mut section = section();
+        section.disabled_functions = vec!["setAcrualPeriod".to_string()];
+
+        let err = Guardrails::from_config(&section).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::Config);
+    }
+
+    #[test]
+    fn duplicate_cellar_sections_are_rejected() {
+        let mut duplicate = section();
+        duplicate.cellar_id = duplicate.cellar_id.to_uppercase();
+
+        assert!(from_sections(&[section()]).is_ok());
+        let err = from_sections(&[section(), duplicate]).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::Config);
+    }
+
+    #[test]
+    fn guardrails_reject_out_of_bounds_arguments() {
+        let guardrails = guardrails();
+        let other = "0x2222222222222222222222222222222222222222"
+            .parse::<H160>()
+            .unwrap();
+
+        assert!(guardrails.check_enabled("setAccrualPeriod").is_err());
+        assert!(guardrails.check_deposit_limit(1001.into()).is_err());
+        assert!(guardrails.check_min_assets_out(9.into()).is_err());
+        assert!(guardrails.check_route(&[other]).is_err());
+        assert!(guardrails.check_swap_types(&[1, 3]).is_err());
+    }
+
+    #[test]
+    fn guardrails_allow_arguments_within_bounds() {
+        let guardrails = guardrails();
+        let allowed = "0x1111111111111111111111111111111111111111"
+            .parse::<H160>()
+            .unwrap();
+
+        assert!(guardrails.check_enabled("rebalance").is_ok());
+        assert!(guardrails.check_deposit_limit(1000.into()).is_ok());
+        assert!(guardrails.check_liquidity_limit(U256::max_value()).is_ok());
+        assert!(guardrails.check_min_assets_out(10.into()).is_ok());
+        assert!(guardrails.check_route(&[allowed, H160::zero()]).is_ok());
+        assert!(guardrails.check_swap_types(&[1, 2]).is_ok());
+    }
+}
@@ -0,0 +1,120 @@
+//! Readiness of the cork server, reported through the standard `grpc.health.v1` service and over
+//! plain HTTP at `/healthz`. Checks run periodically in the background so that probes are cheap.
+//!
+//! The overall server status ("") reports liveness and is always serving. The `ContractCall`
+//! service reports readiness: it is serving only while every check passes.
+use crate::{
+    config, connections,
+    cork::{cache, CorkHandler},
+    prelude::APP,
+};
+use abscissa_core::{
+    tracing::log::{info, warn},
+    Application,
+};
+use deep_space::client::ChainStatus;
+use lazy_static::lazy_static;
+use std::{sync::RwLock, time::Duration};
+use steward_proto::steward::contract_call_server::ContractCallServer;
+use tonic_health::server::HealthReporter;
+
+const CHECK_PERIOD: Duration = Duration::from_secs(10);
+
+lazy_static! {
+    /// Reasons the server isn't ready as of the last run of the checks
+    static ref FAILURES: RwLock<Vec<String>> =
+        RwLock::new(vec!["readiness checks have not run yet".to_string()]);
+}
+
+/// Reasons the server isn't ready, empty if it is
+pub fn failures() -> Vec<String> {
+    match FAILURES.read() {
+        Ok(f) => f.clone(),
+        Err(_) => vec!["readiness state lock poisoned".to_string()],
+    }
+}
+
+/// Runs the checks every period, updating the health service with the result
+pub async fn run_checks(mut reporter: HealthReporter) {
+    let mut ready = false;
+    loop {
+        let failures = check().await;
+        if failures.is_empty() {
+            if !ready {
+                info!("server is ready");
+            }
+            reporter
+                .set_serving::<ContractCallServer<CorkHandler>>()
+                .await;
+        } else {
+            warn!("server is not ready: {}", failures.join("; "));
+            reporter
+                .set_not_serving::<ContractCallServer<CorkHandler>>()
+                .await;
+        }
+        ready = failures.is_empty();
+
+        record(failures);
+        tokio::time::sleep(CHECK_PERIOD).await;
+    }
+}
+
+fn record(failures: Vec<String>) {
+    if let Ok(mut f) = FAILURES.write() {
+        *f = failures;
+    }
+}
+
+async fn check() -> Vec<String> {
+    let config = APP.config();
+    let mut failures = Vec::new();
+
+    // In test mode there is no Sommelier chain or delegate key involved
+    if config.test_mode.enabled {
+        return failures;
+    }
+
+    if let Err(err) = config::delegate_address() {
+        failures.push(err.to_string());
+    }
+
+    match connections::contact(connections::MESSAGE_TIMEOUT) {
+        Ok(contact) => match contact.get_chain_status().await {
+            Ok(ChainStatus::Moving { .. }) => (),
+            Ok(ChainStatus::Syncing) => failures.push("cosmos node is syncing".to_string()),
+            Ok(ChainStatus::WaitingToStart) => {
+                failures.push("cosmos chain has not started".to_string())
+            }
+            Err(err) => failures.push(format!("cosmos node unreachable: {}", err)),
+        },
+        Err(err) => failures.push(format!("cosmos node unreachable: {}", err)),
+    }
+
+    let max_staleness = Duration::from_secs(config.cellar_id_cache.max_staleness);
+    match cache::age() {
+        Some(age) if age <= max_staleness => (),
+        Some(age) => failures.push(format!(
+            "approved cellar IDs are stale, last refreshed {:?} ago",
+            age
+        )),
+        None => failures.push("approved cellar IDs have not been fetched".to_string()),
+    }
+
+    failures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn failures_are_those_of_the_last_run() {
+        assert_eq!(failures(), vec!["readiness checks have not run yet"]);
+
+        record(vec!["cosmos node is syncing".to_string()]);
+        assert_eq!(failures(), vec!["cosmos node is syncing"]);
+
+        record(Vec::new());
+        assert!(failures().is_empty());
+    }
+}
@@ -0,0 +1,646 @@
+//! On-disk journal of submitted corks. Every `Submit` request is recorded along with the cork built
+//! from it and the outcome of sending it. Requests carrying an idempotency key are claimed in the
+//! journal before anything is sent, so that a client retrying after a dropped connection gets the
+//! original result back instead of causing a duplicate cork to be sent. A key is only released for
+//! another attempt if its request failed before the cork was handed off to be signed and broadcast,
+//! since after that the cork may reach the chain whatever error was seen. Keys are scoped to the
+//! client's identity, so clients can't collide with or replay each other's keys. Submissions older
+//! than the configured retention period are pruned, oldest first, as new ones are recorded.
+use crate::{
+    error::{Error, ErrorKind},
+    prelude::APP,
+};
+use abscissa_core::{
+    tracing::log::{info, warn},
+    Application,
+};
+use chrono::{DateTime, Duration, Utc};
+use once_cell::sync::OnceCell;
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::str::FromStr;
+use steward_proto::steward::{SubmitRequest, SubmitResponse};
+
+const SUBMISSIONS_TREE: &str = "submissions";
+const IDEMPOTENCY_KEYS_TREE: &str = "idempotency_keys";
+
+static JOURNAL: OnceCell<Option<Journal>> = OnceCell::new();
+
+/// Opens the journal if it's enabled. Called at startup so that a journal that can't be opened
+/// fails there rather than on the first request.
+pub fn load() -> Result<(), Error> {
+    JOURNAL.get_or_try_init(|| {
+        let config = APP.config();
+        if !config.journal.enabled {
+            return Ok(None);
+        }
+
+        info!("opening submission journal at {}", config.journal.path);
+        Journal::open(&config.journal.path, config.journal.retention_days).map(Some)
+    })?;
+
+    Ok(())
+}
+
+/// Returns the journal, or None if it isn't enabled or hasn't been loaded
+pub fn get() -> Option<&'static Journal> {
+    JOURNAL.get().and_then(|j| j.as_ref())
+}
+
+#[derive(Clone, Copy, Debug, Deserialize, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubmissionStatus {
+    /// Being validated, nothing has been sent
+    Pending,
+    /// Handed off to be signed and broadcast
+    Sending,
+    Succeeded,
+    /// Rejected before anything was sent
+    Failed,
+    /// Sending failed or was interrupted, so the cork may or may not have reached the chain
+    Unknown,
+}
+
+impl SubmissionStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubmissionStatus::Pending => "pending",
+            SubmissionStatus::Sending => "sending",
+            SubmissionStatus::Succeeded => "succeeded",
+            SubmissionStatus::Failed => "failed",
+            SubmissionStatus::Unknown => "unknown",
+        }
+    }
+}
+
+impl FromStr for SubmissionStatus {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Error> {
+        match s {
+            "pending" => Ok(SubmissionStatus::Pending),
+            "sending" => Ok(SubmissionStatus::Sending),
+            "succeeded" => Ok(SubmissionStatus::Succeeded),
+            "failed" => Ok(SubmissionStatus::Failed),
+            "unknown" => Ok(SubmissionStatus::Unknown),
+            _ => Err(ErrorKind::ClientError
+                .context(format!(
+                    "invalid submission status {}, expected pending, sending, succeeded, failed or unknown",
+                    s
+                ))
+                .into()),
+        }
+    }
+}
+
+/// Criteria for listing submissions. Unset fields match every submission.
+#[derive(Clone, Debug, Default)]
+pub struct HistoryFilter {
+    /// Identities of the client whose submissions are listed
+    pub clients: Option<Vec<String>>,
+    pub cellar_id: Option<String>,
+    pub start: Option<DateTime<Utc>>,
+    pub end: Option<DateTime<Utc>>,
+    pub status: Option<SubmissionStatus>,
+    pub limit: usize,
+}
+
+impl HistoryFilter {
+    fn matches(&self, submission: &Submission) -> bool {
+        if let Some(clients) = &self.clients {
+            if !clients.contains(&submission.client) {
+                return false;
+            }
+        }
+        if let Some(cellar_id) = &self.cellar_id {
+            if !submission.cellar_id.eq_ignore_ascii_case(cellar_id) {
+                return false;
+            }
+        }
+        if let Some(status) = self.status {
+            if submission.status != status {
+                return false;
+            }
+        }
+        let created_at = submission.created_at();
+        if self.start.map_or(false, |start| created_at < start) {
+            return false;
+        }
+        if self.end.map_or(false, |end| created_at >= end) {
+            return false;
+        }
+
+        true
+    }
+}
+
+/// A journaled `Submit` request and its outcome
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Submission {
+    pub id: u64,
+    /// Identity of the client that sent the request, empty if it presented no certificate
+    #[serde(default)]
+    pub client: String,
+    pub idempotency_key: String,
+    pub cellar_id: String,
+    /// Hex encoded protobuf `SubmitRequest` as received
+    pub request: String,
+    /// Hex encoded SHA-256 of the request, used to detect an idempotency key reused for a
+    /// different request
+    #[serde(default)]
+    pub request_hash: String,
+    pub cork_id: String,
+    /// Hex encoded contract call sent in the cork
+    pub encoded_call: String,
+    pub status: SubmissionStatus,
+    pub error: String,
+    pub tx_hash: String,
+    pub height: u64,
+    pub gas_used: u64,
+    /// RFC 3339 timestamps
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl Submission {
+    fn new(id: u64, client: &str, request: &SubmitRequest) -> Self {
+        let now = Utc::now().to_rfc3339();
+        let mut encoded_request = Vec::new();
+        // Encoding into a Vec can't run out of capacity
+        request
+            .encode(&mut encoded_request)
+            .expect("failed to encode request");
+        let request_hash = hex::encode(Sha256::digest(&encoded_request));
+        Self {
+            id,
+            client: client.to_string(),
+            idempotency_key: request.idempotency_key.clone(),
+            cellar_id: request.cellar_id.clone(),
+            request: hex::encode(encoded_request),
+            request_hash,
+            cork_id: String::new(),
+            encoded_call: String::new(),
+            status: SubmissionStatus::Pending,
+            error: String::new(),
+            tx_hash: String::new(),
+            height: 0,
+            gas_used: 0,
+            created_at: now.clone(),
+            updated_at: now,
+        }
+    }
+
+    pub fn succeeded(&mut self, response: &SubmitResponse) {
+        self.status = SubmissionStatus::Succeeded;
+        self.cork_id = response.cork_id.clone();
+        self.tx_hash = response.tx_hash.clone();
+        self.height = response.height;
+        self.gas_used = response.gas_used;
+    }
+
+    /// Marks the submission as handed off to be signed and broadcast
+    pub fn sending(&mut self) {
+        self.status = SubmissionStatus::Sending;
+    }
+
+    /// Marks the submission as rejected before its cork was sent
+    pub fn failed(&mut self, error: &str) {
+        self.status = SubmissionStatus::Failed;
+        self.error = error.to_string();
+    }
+
+    /// Marks the submission as failing while its cork was being sent, which may have reached the
+    /// chain regardless
+    pub fn unknown(&mut self, error: &str) {
+        self.status = SubmissionStatus::Unknown;
+        self.error = error.to_string();
+    }
+
+    pub fn created_at(&self) -> DateTime<Utc> {
+        parse_timestamp(&self.created_at)
+    }
+
+    pub fn updated_at(&self) -> DateTime<Utc> {
+        parse_timestamp(&self.updated_at)
+    }
+
+    /// The response originally returned for this submission
+    pub fn to_response(&self) -> SubmitResponse {
+        SubmitResponse {
+            tx_hash: self.tx_hash.clone(),
+            height: self.height,
+            gas_used: self.gas_used,
+            cellar_id: self.cellar_id.clone(),
+            cork_id: self.cork_id.clone(),
+        }
+    }
+}
+
+/// The result of recording a new request in the journal
+pub enum Begin {
+    /// The request was recorded and should be processed
+    New(Submission),
+    /// A request with the same idempotency key already succeeded
+    Replay(Submission),
+    /// A request with the same idempotency key is still being processed
+    InProgress,
+    /// A request with the same idempotency key failed while its cork was being sent, so the cork
+    /// may have reached the chain
+    Unknown(Submission),
+    /// The idempotency key was already used for a different request
+    Conflict,
+}
+
+pub struct Journal {
+    db: sled::Db,
+    submissions: sled::Tree,
+    idempotency_keys: sled::Tree,
+    /// How long submissions are kept, or None to keep them forever
+    retention: Option<Duration>,
+}
+
+impl Journal {
+    /// Opens the journal at `path`, keeping submissions for `retention_days`, or forever if zero
+    pub fn open(path: &str, retention_days: u64) -> Result<Self, Error> {
+        let retention = match retention_days {
+            0 => None,
+            days => Some(Duration::days(days as i64)),
+        };
+
+        Self::from_db(sled::open(path)?, retention)
+    }
+
+    fn from_db(db: sled::Db, retention: Option<Duration>) -> Result<Self, Error> {
+        let submissions = db.open_tree(SUBMISSIONS_TREE)?;
+        let idempotency_keys = db.open_tree(IDEMPOTENCY_KEYS_TREE)?;
+        let journal = Self {
+            db,
+            submissions,
+            idempotency_keys,
+            retention,
+        };
+        journal.recover_pending()?;
+
+        Ok(journal)
+    }
+
+    /// Resolves submissions a previous run was interrupted in the middle of. Those still being
+    /// validated sent nothing, so they're marked failed and release their idempotency keys, while
+    /// those that were being sent may have reached the chain, so they're marked unknown and keep
+    /// their keys. Only one process can hold the journal open, so none of them can still be in
+    /// progress when it's opened.
+    fn recover_pending(&self) -> Result<(), Error> {
+        for entry in self.submissions.iter() {
+            let (_, value) = entry?;
+            let mut submission: Submission = serde_json::from_slice(&value)?;
+            match submission.status {
+                SubmissionStatus::Pending => {
+                    warn!(
+                        "submission {} was interrupted by a restart before it was sent, marking it failed",
+                        submission.id
+                    );
+                    submission.failed("interrupted by a restart before its cork was sent");
+                }
+                SubmissionStatus::Sending => {
+                    warn!(
+                        "submission {} was interrupted by a restart while it was sent, marking it unknown",
+                        submission.id
+                    );
+                    submission.unknown("interrupted by a restart while its cork was sent");
+                }
+                _ => continue,
+            }
+            submission.updated_at = Utc::now().to_rfc3339();
+            self.write(&submission)?;
+        }
+        self.submissions.flush()?;
+
+        Ok(())
+    }
+
+    /// Removes submissions received before the retention period, oldest first, along with their
+    /// claims on idempotency keys
+    fn prune(&self, now: DateTime<Utc>) -> Result<(), Error> {
+        let cutoff = match self.retention {
+            Some(retention) => now - retention,
+            None => return Ok(()),
+        };
+
+        while let Some((key, value)) = self.submissions.first()? {
+            let submission: Submission = serde_json::from_slice(&value)?;
+            if submission.created_at() >= cutoff {
+                break;
+            }
+
+            if !submission.idempotency_key.is_empty() {
+                let idempotency_key =
+                    scoped_idempotency_key(&submission.client, &submission.idempotency_key);
+                // A retry may have claimed the key since, in which case the claim is left alone
+                let _ = self.idempotency_keys.compare_and_swap::<_, _, &[u8]>(
+                    &idempotency_key,
+                    Some(&key),
+                    None,
+                )?;
+            }
+            self.submissions.remove(&key)?;
+        }
+
+        Ok(())
+    }
+
+    /// Records a new request from the client. If the request carries an idempotency key, the
+    /// key is claimed for this submission unless an earlier submission with the same key holds it.
+    /// Only keys whose submissions failed before anything was sent are reclaimed, so the client
+    /// can retry with the same key. A key can only be used again for the same request.
+    pub async fn begin(&self, client: &str, request: &SubmitRequest) -> Result<Begin, Error> {
+        self.prune(Utc::now())?;
+
+        // IDs are monotonic, so big-endian keys keep submissions in the order they were received
+        let id = self.db.generate_id()?;
+        let key = id.to_be_bytes();
+        let submission = Submission::new(id, client, request);
+
+        if !request.idempotency_key.is_empty() {
+            let idempotency_key = scoped_idempotency_key(client, &request.idempotency_key);
+            loop {
+                let previous = self.idempotency_keys.get(&idempotency_key)?;
+                if let Some(previous_key) = previous.as_ref() {
+                    match self.get_by_key(previous_key)? {
+                        Some(s) if s.request_hash != submission.request_hash => {
+                            return Ok(Begin::Conflict)
+                        }
+                        Some(s) if s.status == SubmissionStatus::Succeeded => {
+                            return Ok(Begin::Replay(s))
+                        }
+                        Some(s) if s.status == SubmissionStatus::Unknown => {
+                            return Ok(Begin::Unknown(s))
+                        }
+                        Some(s) if s.status == SubmissionStatus::Failed => (),
+                        // The key is claimed before its submission is written, so a missing
+                        // submission is one that's just starting
+                        _ => return Ok(Begin::InProgress),
+                    }
+                }
+
+                if self
+                    .idempotency_keys
+                    .compare_and_swap(&idempotency_key, previous, Some(&key[..]))?
+                    .is_ok()
+                {
+                    break;
+                }
+            }
+        }
+
+        self.put(&submission).await?;
+
+        Ok(Begin::New(submission))
+    }
+
+    /// Writes the submission's current state
+    pub async fn update(&self, submission: &mut Submission) -> Result<(), Error> {
+        submission.updated_at = Utc::now().to_rfc3339();
+        self.put(submission).await
+    }
+
+    /// Lists submissions matching the filter, most recent first
+    pub fn history(&self, filter: &HistoryFilter) -> Result<Vec<Submission>, Error> {
+        let mut submissions = Vec::new();
+        for entry in self.submissions.iter().rev() {
+            let (_, value) = entry?;
+            let submission: Submission = serde_json::from_slice(&value)?;
+            // Submissions are stored in the order they were received
+            if filter
+                .start
+                .map_or(false, |start| submission.created_at() < start)
+            {
+                break;
+            }
+            if !filter.matches(&submission) {
+                continue;
+            }
+
+            submissions.push(submission);
+            if submissions.len() >= filter.limit {
+                break;
+            }
+        }
+
+        Ok(submissions)
+    }
+
+    pub fn get(&self, id: u64) -> Result<Option<Submission>, Error> {
+        self.get_by_key(&id.to_be_bytes())
+    }
+
+    fn get_by_key(&self, key: &[u8]) -> Result<Option<Submission>, Error> {
+        match self.submissions.get(key)? {
+            Some(value) => Ok(Some(serde_json::from_slice(&value)?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Writes the submission and waits for it to reach the disk, without blocking the runtime
+    async fn put(&self, submission: &Submission) -> Result<(), Error> {
+        self.write(submission)?;
+        self.submissions.flush_async().await?;
+
+        Ok(())
+    }
+
+    fn write(&self, submission: &Submission) -> Result<(), Error> {
+        let value = serde_json::to_vec(submission)?;
+        self.submissions
+            .insert(submission.id.to_be_bytes(), value)?;
+
+        Ok(())
+    }
+}
+
+/// Clients' keys are kept apart by prefixing them with the client's identity. Certificate
+/// identities can't contain a NUL, so it can't be confused with part of the key.
+fn scoped_idempotency_key(client: &str, key: &str) -> Vec<u8> {
+    [client.as_bytes(), &[0], key.as_bytes()].concat()
+}
+
+/// Timestamps are only ever written by this module, so an unparseable one means the journal was
+/// edited by hand. Treat it as the epoch rather than failing the whole query.
+fn parse_timestamp(timestamp: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .map(|t| t.with_timezone(&Utc))
+        .unwrap_or_else(|_| DateTime::<Utc>::from(std::time::UNIX_EPOCH))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    const CLIENT: &str = "strategist-a";
+
+    fn journal() -> Journal {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        Journal::from_db(db, None).unwrap()
+    }
+
+    fn request(cellar_id: &str, idempotency_key: &str) -> SubmitRequest {
+        SubmitRequest {
+            cellar_id: cellar_id.to_string(),
+            idempotency_key: idempotency_key.to_string(),
+            ..Default::default()
+        }
+    }
+
+    fn begin_new(journal: &Journal, client: &str, request: &SubmitRequest) -> Submission {
+        match block_on(journal.begin(client, request)).unwrap() {
+            Begin::New(s) => s,
+            _ => panic!("request was not recorded as new"),
+        }
+    }
+
+    fn response(tx_hash: &str) -> SubmitResponse {
+        SubmitResponse {
+            tx_hash: tx_hash.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn succeeded_requests_are_replayed() {
+        let journal = journal();
+        let request = request("0x01", "key");
+        let mut submission = begin_new(&journal, CLIENT, &request);
+
+        assert!(matches!(
+            block_on(journal.begin(CLIENT, &request)).unwrap(),
+            Begin::InProgress
+        ));
+
+        submission.succeeded(&response("ABCD"));
+        block_on(journal.update(&mut submission)).unwrap();
+        match block_on(journal.begin(CLIENT, &request)).unwrap() {
+            Begin::Replay(s) => assert_eq!(s.tx_hash, "ABCD"),
+            _ => panic!("succeeded request was not replayed"),
+        }
+    }
+
+    #[test]
+    fn failed_requests_release_their_key() {
+        let journal = journal();
+        let request = request("0x01", "key");
+        let mut submission = begin_new(&journal, CLIENT, &request);
+
+        submission.failed("node unreachable");
+        block_on(journal.update(&mut submission)).unwrap();
+        let retry = begin_new(&journal, CLIENT, &request);
+        assert_ne!(retry.id, submission.id);
+    }
+
+    #[test]
+    fn requests_that_failed_while_sending_keep_their_key() {
+        let journal = journal();
+        let request = request("0x01", "key");
+        let mut submission = begin_new(&journal, CLIENT, &request);
+
+        submission.sending();
+        block_on(journal.update(&mut submission)).unwrap();
+        assert!(matches!(
+            block_on(journal.begin(CLIENT, &request)).unwrap(),
+            Begin::InProgress
+        ));
+
+        submission.unknown("transaction was not committed before the timeout");
+        block_on(journal.update(&mut submission)).unwrap();
+        match block_on(journal.begin(CLIENT, &request)).unwrap() {
+            Begin::Unknown(s) => assert_eq!(s.id, submission.id),
+            _ => panic!("key of a possibly sent request was released"),
+        }
+    }
+
+    #[test]
+    fn reused_key_for_different_request_conflicts() {
+        let journal = journal();
+        begin_new(&journal, CLIENT, &request("0x01", "key"));
+
+        assert!(matches!(
+            block_on(journal.begin(CLIENT, &request("0x02", "key"))).unwrap(),
+            Begin::Conflict
+        ));
+    }
+
+    #[test]
+    fn keys_are_scoped_to_the_client() {
+        let journal = journal();
+        let request = request("0x01", "key");
+        let mut submission = begin_new(&journal, CLIENT, &request);
+        submission.succeeded(&response("ABCD"));
+        block_on(journal.update(&mut submission)).unwrap();
+
+        let other = begin_new(&journal, "strategist-b", &request);
+        assert_eq!(other.client, "strategist-b");
+    }
+
+    #[test]
+    fn history_is_filtered_by_client() {
+        let journal = journal();
+        begin_new(&journal, CLIENT, &request("0x01", ""));
+        begin_new(&journal, "strategist-b", &request("0x02", ""));
+
+        let filter = HistoryFilter {
+            clients: Some(vec![CLIENT.to_string(), "CN=strategist-a".to_string()]),
+            limit: 10,
+            ..Default::default()
+        };
+        let history = journal.history(&filter).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0].cellar_id, "0x01");
+
+        let filter = HistoryFilter {
+            limit: 10,
+            ..Default::default()
+        };
+        assert_eq!(journal.history(&filter).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn interrupted_submissions_are_resolved_when_reopened() {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        let journal = Journal::from_db(db.clone(), None).unwrap();
+        let pending = request("0x01", "pending");
+        let submission = begin_new(&journal, CLIENT, &pending);
+        let sending = request("0x01", "sending");
+        let mut sent = begin_new(&journal, CLIENT, &sending);
+        sent.sending();
+        block_on(journal.update(&mut sent)).unwrap();
+
+        let journal = Journal::from_db(db, None).unwrap();
+        let recovered = journal.get(submission.id).unwrap().unwrap();
+        assert_eq!(recovered.status, SubmissionStatus::Failed);
+        begin_new(&journal, CLIENT, &pending);
+
+        let recovered = journal.get(sent.id).unwrap().unwrap();
+        assert_eq!(recovered.status, SubmissionStatus::Unknown);
+        assert!(matches!(
+            block_on(journal.begin(CLIENT, &sending)).unwrap(),
+            Begin::Unknown(_)
+        ));
+    }
+
+    #[test]
+    fn submissions_older_than_the_retention_period_are_pruned() {
+        let db = sled::Config::new().temporary(true).open().unwrap();
+        let journal = Journal::from_db(db, Some(Duration::days(30))).unwrap();
+        let keyed = request("0x01", "key");
+        let mut old = begin_new(&journal, CLIENT, &keyed);
+        old.created_at = (Utc::now() - Duration::days(31)).to_rfc3339();
+        old.succeeded(&response("ABCD"));
+        block_on(journal.update(&mut old)).unwrap();
+
+        let recent = begin_new(&journal, CLIENT, &request("0x02", ""));
+        assert!(journal.get(old.id).unwrap().is_none());
+        assert!(journal.get(recent.id).unwrap().is_some());
+        // The pruned submission no longer holds its key
+        begin_new(&journal, CLIENT, &keyed);
+    }
+}
@@ -10,13 +10,22 @@
 #![warn(rust_2018_idioms, trivial_casts)]
 
 pub mod application;
+pub mod authorization;
+pub mod cellar_query;
 pub mod cellars;
 pub mod commands;
 pub mod config;
+pub mod connections;
 pub mod cork;
 pub mod error;
+pub mod eth_send;
 pub mod gas;
+pub mod guardrails;
+pub mod health;
+pub mod journal;
+pub mod metrics;
 pub mod prelude;
+pub mod rate_limit;
 pub mod server;
 pub mod somm_send;
 pub mod utils;
@@ -0,0 +1,223 @@
+//! Prometheus metrics for the cork server, served over HTTP by `steward start` along with the
+//! `/healthz` readiness endpoint
+use crate::{cork::cache, health};
+use abscissa_core::tracing::log::{error, info};
+use hyper::{
+    header::CONTENT_TYPE,
+    service::{make_service_fn, service_fn},
+    Body, Request, Response, Server, StatusCode,
+};
+use lazy_static::lazy_static;
+use prometheus::{
+    core::Collector, Encoder, Gauge, GaugeVec, Histogram, HistogramOpts, IntCounterVec, Opts,
+    TextEncoder,
+};
+use std::{convert::Infallible, net::SocketAddr};
+use tonic::Code;
+
+lazy_static! {
+    /// Labeled by cellar only once the cellar is known to be approved, so that clients can't
+    /// create arbitrarily many series. Test mode doesn't check approval, so there every cellar is
+    /// labeled `test_mode`.
+    pub static ref CORK_REQUESTS: IntCounterVec = register(
+        IntCounterVec::new(
+            Opts::new(
+                "steward_cork_requests_total",
+                "Contract call requests for approved cellars, by cellar and function",
+            ),
+            &["cellar_id", "function"],
+        )
+        .unwrap(),
+    );
+    pub static ref CORK_REJECTIONS: IntCounterVec = register(
+        IntCounterVec::new(
+            Opts::new(
+                "steward_cork_rejections_total",
+                "Contract call requests that were rejected or failed to send, by reason",
+            ),
+            &["reason"],
+        )
+        .unwrap(),
+    );
+    pub static ref ENCODE_LATENCY: Histogram = register(
+        Histogram::with_opts(
+            HistogramOpts::new(
+                "steward_cork_encode_seconds",
+                "Time taken to validate and ABI encode a contract call",
+            )
+            .buckets(vec![0.0001, 0.0005, 0.001, 0.005, 0.01, 0.05, 0.1]),
+        )
+        .unwrap(),
+    );
+    pub static ref BROADCAST_LATENCY: Histogram = register(
+        Histogram::with_opts(
+            HistogramOpts::new(
+                "steward_tx_broadcast_seconds",
+                "Time taken by each attempt to broadcast a cork transaction and wait for it to be committed",
+            )
+            .buckets(vec![0.1, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0]),
+        )
+        .unwrap(),
+    );
+    pub static ref FEES_PAID: IntCounterVec = register(
+        IntCounterVec::new(
+            Opts::new(
+                "steward_tx_fees_paid_total",
+                "Fees charged for cork transactions committed to a block, by denom",
+            ),
+            &["denom"],
+        )
+        .unwrap(),
+    );
+    /// Keyed by client identity or approved cellar ID
+    pub static ref RATE_LIMIT_TOKENS: GaugeVec = register(
+        GaugeVec::new(
+            Opts::new(
+                "steward_rate_limit_tokens",
+                "Requests left in each rate limit bucket as of its last request, by scope and key",
+            ),
+            &["scope", "key"],
+        )
+        .unwrap(),
+    );
+    /// Set when metrics are served, +Inf if the cache has never been refreshed
+    pub static ref CELLAR_ID_CACHE_AGE: Gauge = register(
+        Gauge::with_opts(Opts::new(
+            "steward_cellar_id_cache_age_seconds",
+            "Time since the cache of approved cellar IDs was last refreshed",
+        ))
+        .unwrap(),
+    );
+    pub static ref RATE_LIMITED: IntCounterVec = register(
+        IntCounterVec::new(
+            Opts::new(
+                "steward_rate_limited_total",
+                "Requests rejected by a rate limit, by scope and key",
+            ),
+            &["scope", "key"],
+        )
+        .unwrap(),
+    );
+}
+
+/// Registers the metric with the default registry, which is what gets served
+fn register<T: Collector + Clone + 'static>(metric: T) -> T {
+    prometheus::register(Box::new(metric.clone())).expect("failed to register metric");
+    metric
+}
+
+/// Counts a rejected request under a reason derived from the status code returned to the client
+pub fn record_rejection(code: Code) {
+    CORK_REJECTIONS
+        .with_label_values(&[rejection_reason(code)])
+        .inc();
+}
+
+/// Counts a request rejected because its cellar isn't approved by governance. These are rejected
+/// with `PermissionDenied`, like clients denied by the authorization policy, so the caller counts
+/// them here rather than by their status code.
+pub fn record_unapproved() {
+    CORK_REJECTIONS.with_label_values(&["unapproved"]).inc();
+}
+
+fn rejection_reason(code: Code) -> &'static str {
+    match code {
+        Code::PermissionDenied => "unauthorized",
+        Code::InvalidArgument => "invalid_argument",
+        Code::Internal => "send_failure",
+        Code::Unavailable => "unavailable",
+        Code::FailedPrecondition => "failed_precondition",
+        Code::Aborted => "in_progress",
+        Code::ResourceExhausted => "rate_limited",
+        _ => "other",
+    }
+}
+
+/// Serves the metrics from the default registry, and readiness at `/healthz`, until the server
+/// fails
+pub async fn serve(addr: SocketAddr) {
+    let make_service = make_service_fn(|_| async { Ok::<_, Infallible>(service_fn(handle)) });
+
+    info!("serving metrics on http://{}/metrics", addr);
+    if let Err(err) = Server::bind(&addr).serve(make_service).await {
+        error!("metrics server error: {}", err);
+    }
+}
+
+async fn handle(request: Request<Body>) -> Result<Response<Body>, Infallible> {
+    match request.uri().path() {
+        "/healthz" => Ok(healthz_response(&health::failures())),
+        _ => Ok(metrics_response()),
+    }
+}
+
+/// 200 if the server is ready, otherwise 503 with the reasons it isn't
+fn healthz_response(failures: &[String]) -> Response<Body> {
+    let (status, body) = if failures.is_empty() {
+        (StatusCode::OK, "ok\n".to_string())
+    } else {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            format!("{}\n", failures.join("\n")),
+        )
+    };
+
+    Response::builder()
+        .status(status)
+        .body(Body::from(body))
+        .unwrap()
+}
+
+fn metrics_response() -> Response<Body> {
+    CELLAR_ID_CACHE_AGE.set(cache::age().map_or(f64::INFINITY, |age| age.as_secs_f64()));
+
+    let encoder = TextEncoder::new();
+    let mut buffer = Vec::new();
+    if let Err(err) = encoder.encode(&prometheus::gather(), &mut buffer) {
+        error!("failed to encode metrics: {}", err);
+    }
+
+    Response::builder()
+        .header(CONTENT_TYPE, encoder.format_type())
+        .body(Body::from(buffer))
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn rejections_are_counted_by_status_code() {
+        assert_eq!(rejection_reason(Code::PermissionDenied), "unauthorized");
+        assert_eq!(rejection_reason(Code::ResourceExhausted), "rate_limited");
+        assert_eq!(rejection_reason(Code::NotFound), "other");
+    }
+
+    fn body(response: Response<Body>) -> String {
+        let bytes = block_on(hyper::body::to_bytes(response.into_body())).unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[test]
+    fn healthz_is_ok_when_ready() {
+        let response = healthz_response(&[]);
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(body(response), "ok\n");
+    }
+
+    #[test]
+    fn healthz_lists_failures_when_not_ready() {
+        let failures = vec![
+            "cosmos node is syncing".to_string(),
+            "approved cellar IDs have not been fetched".to_string(),
+        ];
+        let response = healthz_response(&failures);
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(
+            body(response),
+            "cosmos node is syncing\napproved cellar IDs have not been fetched\n"
+        );
+    }
+}
@@ -0,0 +1,178 @@
+//! Token bucket rate limits on contract call requests, per client identity and per cellar, so that
+//! a misbehaving client can't drain the delegate account's fees or flood the cork module. Limited
+//! requests are rejected with `ResourceExhausted` and a `retry-after` metadata entry giving the
+//! number of seconds until the request would be allowed.
+use crate::{metrics, prelude::APP};
+use abscissa_core::{tracing::log::info, Application};
+use lazy_static::lazy_static;
+use std::{
+    collections::HashMap,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+use tonic::{Code, Status};
+
+lazy_static! {
+    static ref CLIENT_LIMITER: Option<Limiter> = {
+        let config = &APP.config().rate_limit;
+        Limiter::new(
+            "client",
+            config.client_requests_per_minute,
+            config.client_burst,
+        )
+    };
+    static ref CELLAR_LIMITER: Option<Limiter> = {
+        let config = &APP.config().rate_limit;
+        Limiter::new(
+            "cellar",
+            config.cellar_requests_per_minute,
+            config.cellar_burst,
+        )
+    };
+}
+
+/// Takes `cost` requests from the client's bucket. Clients are keyed by their first identity,
+/// the common name of their certificate.
+pub fn check_client(identities: &[String], cost: u32) -> Result<(), Status> {
+    match CLIENT_LIMITER.as_ref() {
+        Some(limiter) => limiter.check(identities.first().map_or("unknown", |i| i.as_str()), cost),
+        None => Ok(()),
+    }
+}
+
+/// Takes a request from the cellar's bucket. Only call this for cellars that are known to be
+/// approved, keyed by `cache::cellar_key`, so that clients can't create arbitrarily many buckets.
+pub fn check_cellar(cellar_id: &str) -> Result<(), Status> {
+    match CELLAR_LIMITER.as_ref() {
+        Some(limiter) => limiter.check(&cellar_id.to_lowercase(), 1),
+        None => Ok(()),
+    }
+}
+
+pub struct Limiter {
+    scope: &'static str,
+    /// Requests added to each bucket per second
+    rate: f64,
+    burst: f64,
+    buckets: Mutex<HashMap<String, Bucket>>,
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+impl Limiter {
+    /// Returns None if the limit is disabled
+    pub fn new(scope: &'static str, requests_per_minute: u32, burst: u32) -> Option<Self> {
+        if requests_per_minute == 0 {
+            return None;
+        }
+        info!(
+            "limiting each {} to {} requests per minute with bursts of {}",
+            scope,
+            requests_per_minute,
+            burst.max(1)
+        );
+
+        Some(Limiter {
+            scope,
+            rate: requests_per_minute as f64 / 60.0,
+            burst: burst.max(1) as f64,
+            buckets: Mutex::new(HashMap::new()),
+        })
+    }
+
+    fn check(&self, key: &str, cost: u32) -> Result<(), Status> {
+        if cost as f64 > self.burst {
+            metrics::RATE_LIMITED
+                .with_label_values(&[self.scope, key])
+                .inc();
+            return Err(Status::new(
+                Code::ResourceExhausted,
+                format!(
+                    "request of {} calls exceeds the {} rate limit burst of {}",
+                    cost, self.scope, self.burst
+                ),
+            ));
+        }
+
+        let (remaining, retry_after) = self.take(key, cost, Instant::now());
+        metrics::RATE_LIMIT_TOKENS
+            .with_label_values(&[self.scope, key])
+            .set(remaining);
+        let retry_after = match retry_after {
+            Some(r) => r,
+            None => return Ok(()),
+        };
+
+        metrics::RATE_LIMITED
+            .with_label_values(&[self.scope, key])
+            .inc();
+        let seconds = (retry_after.as_secs_f64().ceil() as u64).max(1);
+        info!(
+            "rate limiting {} {}, retry after {}s",
+            self.scope, key, seconds
+        );
+        let mut status = Status::new(
+            Code::ResourceExhausted,
+            format!(
+                "{} rate limit exceeded for {}, retry after {}s",
+                self.scope, key, seconds
+            ),
+        );
+        if let Ok(value) = seconds.to_string().parse() {
+            status.metadata_mut().insert("retry-after", value);
+        }
+
+        Err(status)
+    }
+
+    /// Refills the key's bucket and takes `cost` tokens from it if there are enough. Returns the
+    /// tokens left in the bucket, and how long until there would have been enough if there weren't.
+    fn take(&self, key: &str, cost: u32, now: Instant) -> (f64, Option<Duration>) {
+        let mut buckets = self.buckets.lock().expect("rate limit lock poisoned");
+        let bucket = buckets.entry(key.to_string()).or_insert(Bucket {
+            tokens: self.burst,
+            updated: now,
+        });
+        let elapsed = now.saturating_duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.rate).min(self.burst);
+        bucket.updated = now;
+
+        let cost = cost as f64;
+        if bucket.tokens >= cost {
+            bucket.tokens -= cost;
+            return (bucket.tokens, None);
+        }
+
+        let wait = Duration::from_secs_f64((cost - bucket.tokens) / self.rate);
+        (bucket.tokens, Some(wait))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_allows_burst_then_refills() {
+        let limiter = Limiter::new("client", 60, 2).unwrap();
+        let start = Instant::now();
+
+        assert_eq!(limiter.take("a", 1, start).1, None);
+        assert_eq!(limiter.take("a", 1, start).1, None);
+        let (_, retry_after) = limiter.take("a", 1, start);
+        assert_eq!(retry_after, Some(Duration::from_secs(1)));
+
+        // Other keys have their own bucket
+        assert_eq!(limiter.take("b", 1, start).1, None);
+
+        assert_eq!(limiter.take("a", 1, start + Duration::from_secs(1)).1, None);
+    }
+
+    #[test]
+    fn zero_rate_disables_limit() {
+        assert!(Limiter::new("cellar", 0, 5).is_none());
+    }
+}
@@ -1,37 +1,372 @@
-use std::net::SocketAddr;
+//! TLS for the cork server. The server certificate, key, client CA and certificate revocation list
+//! are reloaded in the background when their files change, so that certificates can be rotated or
+//! revoked without restarting the server. New connections use the latest configuration and
+//! connections that are already open are left alone. The `revoked_serials` denylist is part of the
+//! config file, which is only read at startup, so changes to it need a restart.
+use std::{
+    collections::HashSet,
+    io::{self, Cursor},
+    net::SocketAddr,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
 
-use crate::{config::StewardConfig, error::Error};
-use tonic::transport::{Certificate, Identity, ServerTlsConfig};
+use crate::{
+    config::StewardConfig,
+    error::{Error, ErrorKind},
+};
+use abscissa_core::tracing::log::{debug, info, warn};
+use futures::Stream;
+use tokio::{
+    net::{TcpListener, TcpStream},
+    sync::mpsc,
+};
+use tokio_rustls::{
+    rustls::{
+        internal::pemfile, AllowAnyAuthenticatedClient, RootCertStore,
+        ServerConfig as RustlsServerConfig, Session,
+    },
+    server::TlsStream,
+    TlsAcceptor,
+};
+use x509_parser::{parse_x509_certificate, parse_x509_crl, pem::Pem};
 
 pub const DEFAULT_CLIENT_CA: &[u8] = include_bytes!("../../tls/peggyjv_ca.crt");
 // for gRPC reflection
 pub const DESCRIPTOR: &[u8] = include_bytes!("../../steward_proto/src/prost/descriptor.bin");
 
+const ALPN_H2: &[u8] = b"h2";
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+const ACCEPT_ERROR_BACKOFF: Duration = Duration::from_millis(100);
+
 pub struct ServerConfig {
-    pub tls_config: ServerTlsConfig,
+    pub tls: Arc<ReloadableTls>,
     pub address: SocketAddr,
 }
 
-pub async fn load_server_config(
-    config: &std::sync::Arc<StewardConfig>,
-) -> Result<ServerConfig, Error> {
-    let cert = tokio::fs::read(&config.server.server_cert_path).await?;
-    let key = tokio::fs::read(&config.server.server_key_path).await?;
-    let server_identity = Identity::from_pem(cert, key);
-    let client_ca = match &config.server.client_ca_cert_path {
-        Some(path) => tokio::fs::read(path).await?,
-        None => DEFAULT_CLIENT_CA.into(),
-    };
-    let client_ca_cert = Certificate::from_pem(client_ca);
-    let tls_config = ServerTlsConfig::new()
-        .identity(server_identity.clone())
-        .client_ca_root(client_ca_cert);
+pub async fn load_server_config(config: &Arc<StewardConfig>) -> Result<ServerConfig, Error> {
+    let files = TlsFiles::read(config).await?;
+    let tls = TlsState::new(files, &config.server.revoked_serials)?;
     let port = &config.server.port;
     let address = &config.server.address;
     let address: SocketAddr = format!("{}:{}", address, port).parse()?;
 
     Ok(ServerConfig {
-        tls_config,
+        tls: Arc::new(ReloadableTls {
+            state: RwLock::new(Arc::new(tls)),
+        }),
         address,
     })
 }
+
+/// Contents of the files the TLS configuration is built from
+#[derive(PartialEq)]
+struct TlsFiles {
+    cert: Vec<u8>,
+    key: Vec<u8>,
+    client_ca: Vec<u8>,
+    crl: Option<Vec<u8>>,
+}
+
+impl TlsFiles {
+    async fn read(config: &StewardConfig) -> Result<Self, Error> {
+        let server = &config.server;
+        Ok(TlsFiles {
+            cert: read(&server.server_cert_path).await?,
+            key: read(&server.server_key_path).await?,
+            client_ca: match &server.client_ca_cert_path {
+                Some(path) => read(path).await?,
+                None => DEFAULT_CLIENT_CA.into(),
+            },
+            crl: match &server.crl_path {
+                Some(path) => Some(read(path).await?),
+                None => None,
+            },
+        })
+    }
+}
+
+async fn read(path: &str) -> Result<Vec<u8>, Error> {
+    tokio::fs::read(path).await.map_err(|err| {
+        ErrorKind::Io
+            .context(format!("failed to read {}: {}", path, err))
+            .into()
+    })
+}
+
+struct TlsState {
+    files: TlsFiles,
+    acceptor: TlsAcceptor,
+    /// Normalized serial numbers of client certificates that are refused
+    revoked_serials: HashSet<String>,
+}
+
+impl TlsState {
+    fn new(files: TlsFiles, denylist: &[String]) -> Result<Self, Error> {
+        let mut client_roots = RootCertStore::empty();
+        match client_roots.add_pem_file(&mut Cursor::new(&files.client_ca)) {
+            Ok((added, _)) if added > 0 => (),
+            _ => return Err(tls_error("no valid certificates in the client CA")),
+        }
+
+        let certs = pemfile::certs(&mut Cursor::new(&files.cert))
+            .map_err(|_| tls_error("invalid server certificate"))?;
+        let key = load_key(&files.key)?;
+        let mut config = RustlsServerConfig::new(AllowAnyAuthenticatedClient::new(client_roots));
+        config
+            .set_single_cert(certs, key)
+            .map_err(|err| tls_error(&format!("invalid server certificate or key: {}", err)))?;
+        config.set_protocols(&[ALPN_H2.to_vec()]);
+
+        let mut revoked_serials: HashSet<String> =
+            denylist.iter().map(|s| normalize_serial(s)).collect();
+        if let Some(crl) = &files.crl {
+            revoked_serials.extend(crl_serials(crl, &files.client_ca)?);
+        }
+
+        Ok(TlsState {
+            files,
+            acceptor: TlsAcceptor::from(Arc::new(config)),
+            revoked_serials,
+        })
+    }
+
+    /// Completes the TLS handshake and refuses clients whose certificate has been revoked
+    async fn accept(&self, tcp: TcpStream) -> Result<TlsStream<TcpStream>, Error> {
+        let stream = tokio::time::timeout(HANDSHAKE_TIMEOUT, self.acceptor.accept(tcp))
+            .await
+            .map_err(|_| tls_error("handshake timed out"))??;
+
+        let (_, session) = stream.get_ref();
+        let certs = session.get_peer_certificates().unwrap_or_default();
+        let serial = match certs.first().map(|c| parse_x509_certificate(&c.0)) {
+            Some(Ok((_, cert))) => hex::encode(cert.raw_serial()),
+            _ => return Err(tls_error("invalid client certificate")),
+        };
+        let serial = normalize_serial(&serial);
+        if self.revoked_serials.contains(&serial) {
+            return Err(tls_error(&format!(
+                "client certificate with serial {} is revoked",
+                serial
+            )));
+        }
+
+        Ok(stream)
+    }
+}
+
+fn load_key(pem: &[u8]) -> Result<tokio_rustls::rustls::PrivateKey, Error> {
+    if let Ok(mut keys) = pemfile::pkcs8_private_keys(&mut Cursor::new(pem)) {
+        if !keys.is_empty() {
+            return Ok(keys.remove(0));
+        }
+    }
+    if let Ok(mut keys) = pemfile::rsa_private_keys(&mut Cursor::new(pem)) {
+        if !keys.is_empty() {
+            return Ok(keys.remove(0));
+        }
+    }
+
+    Err(tls_error("no valid private key in the server key file"))
+}
+
+/// Serial numbers revoked by a PEM or DER encoded certificate revocation list, which must name one
+/// of the client CA certificates as its issuer. The list's signature isn't verified: like the
+/// client CA itself, it's read from local disk and trusted as configuration.
+fn crl_serials(crl: &[u8], client_ca: &[u8]) -> Result<Vec<String>, Error> {
+    let der = match Pem::iter_from_buffer(crl).next() {
+        Some(Ok(pem)) => pem.contents,
+        _ => crl.to_vec(),
+    };
+    let (_, crl) = parse_x509_crl(&der)
+        .map_err(|err| tls_error(&format!("invalid certificate revocation list: {}", err)))?;
+
+    let issuer = crl.issuer().as_raw();
+    if !ca_subjects(client_ca)
+        .iter()
+        .any(|s| s.as_slice() == issuer)
+    {
+        return Err(tls_error(&format!(
+            "certificate revocation list issuer {} is not a client CA",
+            crl.issuer()
+        )));
+    }
+
+    Ok(crl
+        .iter_revoked_certificates()
+        .map(|c| normalize_serial(&hex::encode(c.raw_serial())))
+        .collect())
+}
+
+/// DER encoded subjects of the certificates in a PEM bundle
+fn ca_subjects(pem: &[u8]) -> Vec<Vec<u8>> {
+    Pem::iter_from_buffer(pem)
+        .filter_map(|pem| pem.ok())
+        .filter_map(|pem| {
+            parse_x509_certificate(&pem.contents)
+                .ok()
+                .map(|(_, cert)| cert.subject().as_raw().to_vec())
+        })
+        .collect()
+}
+
+/// Lowercase hex without separators or leading zeros, so that serials copied from `openssl x509
+/// -serial` (`01A2...`) and from other tools (`01:a2:...`) compare equal
+fn normalize_serial(serial: &str) -> String {
+    let serial: String = serial
+        .trim()
+        .trim_start_matches("0x")
+        .chars()
+        .filter(|c| *c != ':')
+        .collect::<String>()
+        .to_lowercase();
+    let serial = serial.trim_start_matches('0');
+    if serial.is_empty() {
+        "0".to_string()
+    } else {
+        serial.to_string()
+    }
+}
+
+fn tls_error(message: &str) -> Error {
+    ErrorKind::Tls.context(message.to_string()).into()
+}
+
+/// The server's current TLS configuration
+pub struct ReloadableTls {
+    state: RwLock<Arc<TlsState>>,
+}
+
+impl ReloadableTls {
+    fn current(&self) -> Arc<TlsState> {
+        self.state.read().expect("TLS state lock poisoned").clone()
+    }
+
+    /// Rereads the TLS files every period and swaps in a new configuration when any of them has
+    /// changed. A configuration that fails to load is logged and the previous one is kept.
+    pub async fn run_reloader(self: Arc<Self>, config: Arc<StewardConfig>) {
+        let period = Duration::from_secs(config.server.certificate_reload_period);
+        loop {
+            tokio::time::sleep(period).await;
+
+            let files = match TlsFiles::read(&config).await {
+                Ok(f) => f,
+                Err(err) => {
+                    warn!("failed to reload TLS configuration: {}", err);
+                    continue;
+                }
+            };
+            if files == self.current().files {
+                continue;
+            }
+
+            match TlsState::new(files, &config.server.revoked_serials) {
+                Ok(state) => {
+                    info!(
+                        "reloaded TLS configuration, {} revoked client certificates",
+                        state.revoked_serials.len()
+                    );
+                    *self.state.write().expect("TLS state lock poisoned") = Arc::new(state);
+                }
+                Err(err) => warn!("failed to reload TLS configuration: {}", err),
+            }
+        }
+    }
+}
+
+/// Accepts connections on the listener, yielding those that complete the TLS handshake with the
+/// current configuration. Handshakes run in their own tasks so that a slow client doesn't hold up
+/// the others.
+pub fn incoming(
+    listener: TcpListener,
+    tls: Arc<ReloadableTls>,
+) -> impl Stream<Item = Result<TlsStream<TcpStream>, io::Error>> {
+    let (tx, rx) = mpsc::channel(32);
+    tokio::spawn(async move {
+        loop {
+            let (tcp, addr) = match listener.accept().await {
+                Ok(c) => c,
+                Err(err) => {
+                    warn!("failed to accept connection: {}", err);
+                    tokio::time::sleep(ACCEPT_ERROR_BACKOFF).await;
+                    continue;
+                }
+            };
+
+            let tls = tls.current();
+            let tx = tx.clone();
+            tokio::spawn(async move {
+                match tls.accept(tcp).await {
+                    Ok(stream) => {
+                        let _ = tx.send(Ok(stream)).await;
+                    }
+                    Err(err) => debug!("refused connection from {}: {}", addr, err),
+                }
+            });
+        }
+    });
+
+    futures::stream::unfold(rx, |mut rx| async move {
+        rx.recv().await.map(|stream| (stream, rx))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CLIENT_CA: &str = "-----BEGIN CERTIFICATE-----
+MIIBhzCCAS2gAwIBAgIUP3VGMIePRdREpzsIyXIALj+OzvYwCgYIKoZIzj0EAwIw
+GTEXMBUGA1UEAwwOdGVzdCBjbGllbnQgQ0EwHhcNMjYxMDE3MDA0MDM2WhcNMzYx
+MDE0MDA0MDM2WjAZMRcwFQYDVQQDDA50ZXN0IGNsaWVudCBDQTBZMBMGByqGSM49
+AgEGCCqGSM49AwEHA0IABN3v5QKWyH6Gi4cu2/+uX8f12903JbN+w1eDu4QGKeco
+vj0+qweLk4eDpHmET79W5ZDlFuSg8E1Yf4u9rFtoiYGjUzBRMB0GA1UdDgQWBBRu
+eKTFf5w1u4aQrhpAgmzsnBqXGDAfBgNVHSMEGDAWgBRueKTFf5w1u4aQrhpAgmzs
+nBqXGDAPBgNVHRMBAf8EBTADAQH/MAoGCCqGSM49BAMCA0gAMEUCIQDrhyG2YxVA
+0bGs55XpZ04t9d/k+hBnEsNexdlAQ59ieQIgPrikrPD3y695jajCVEyOrlT3oNrN
+mfsyDYKvA5EHdCU=
+-----END CERTIFICATE-----
+";
+
+    const OTHER_CA: &str = "-----BEGIN CERTIFICATE-----
+MIIBfDCCASGgAwIBAgIUNfb0RZ451pfPPrwjCquaZB5JVd0wCgYIKoZIzj0EAwIw
+EzERMA8GA1UEAwwIb3RoZXIgQ0EwHhcNMjYxMDE3MDA0MDM2WhcNMzYxMDE0MDA0
+MDM2WjATMREwDwYDVQQDDAhvdGhlciBDQTBZMBMGByqGSM49AgEGCCqGSM49AwEH
+A0IABCAR371y5VXTTXhc71d5ZopC0Zo8zc1W8m6v3euGsqzGza9jqegRgupkoShN
+WrikDGNSvWraGLKeOgVBCXvDQ0yjUzBRMB0GA1UdDgQWBBSc+Ar91hchV0bsRm6h
+OEF/njOFrDAfBgNVHSMEGDAWgBSc+Ar91hchV0bsRm6hOEF/njOFrDAPBgNVHRMB
+Af8EBTADAQH/MAoGCCqGSM49BAMCA0kAMEYCIQD3p11ARa/oKVQZVymyGJxF6hE8
+S+LU04ES7IrLxKafNQIhAOZgfWgDUcF/bzGpMokuOBGshTKLxt3P21dGNYgjqC5z
+-----END CERTIFICATE-----
+";
+
+    /// Issued by `CLIENT_CA`, revoking serial 1A2B3C
+    const CRL: &str = "-----BEGIN X509 CRL-----
+MIHIMHACAQEwCgYIKoZIzj0EAwIwGTEXMBUGA1UEAwwOdGVzdCBjbGllbnQgQ0EX
+DTI2MTAxNzAwNDAzNloXDTM2MTAxNDAwNDAzNlowFjAUAgMaKzwXDTI2MTAxNzAw
+NDAzNlqgDjAMMAoGA1UdFAQDAgEBMAoGCCqGSM49BAMCA0gAMEUCIH6yKOHBoAfh
+NRUgHpmg/AUgAErLQJW6e93nqYUI209hAiEA1VvdojW25BqmBbKOsltrFrmRgVOp
+aIjJNDBMjmQGeA0=
+-----END X509 CRL-----
+";
+
+    #[test]
+    fn crl_must_be_issued_by_client_ca() {
+        let serials = crl_serials(CRL.as_bytes(), CLIENT_CA.as_bytes()).unwrap();
+        assert_eq!(serials, vec!["1a2b3c"]);
+
+        let bundle = format!("{}{}", OTHER_CA, CLIENT_CA);
+        assert!(crl_serials(CRL.as_bytes(), bundle.as_bytes()).is_ok());
+
+        let err = crl_serials(CRL.as_bytes(), OTHER_CA.as_bytes()).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::Tls);
+    }
+
+    #[test]
+    fn serials_compare_equal_across_formats() {
+        assert_eq!(normalize_serial("01:A2:0F"), "1a20f");
+        assert_eq!(normalize_serial("0x01a20f"), "1a20f");
+        assert_eq!(normalize_serial(" 01A20F\n"), "1a20f");
+        assert_eq!(normalize_serial("00"), "0");
+    }
+}
@@ -1,3 +1,9 @@
+use crate::{
+    connections,
+    error::{Error, ErrorKind},
+    metrics,
+};
+use abscissa_core::tracing::log::{debug, info, warn};
 use deep_space::coin::Coin;
 use deep_space::error::CosmosGrpcError;
 use deep_space::Contact;
@@ -5,28 +11,98 @@ use deep_space::Fee;
 use deep_space::Msg;
 use deep_space::PrivateKey as CosmosPrivateKey;
 use gravity_bridge::gravity_proto::cosmos_sdk_proto::cosmos::{
-    base::abci::v1beta1::TxResponse, tx::v1beta1::BroadcastMode,
+    base::abci::v1beta1::TxResponse,
+    tx::v1beta1::{
+        service_client::ServiceClient as TxServiceClient, AuthInfo, BroadcastMode, GetTxRequest,
+        SimulateRequest, Tx, TxBody, TxRaw,
+    },
 };
+use lazy_static::lazy_static;
+use prost::Message;
 use somm_proto::cork::Cork;
 use somm_proto::cork::{MsgScheduleCorkRequest, MsgSubmitCorkRequest};
-use std::{result::Result, time::Duration};
+use std::{cmp::max, collections::HashMap, result::Result, time::Duration};
+use tokio::sync::Mutex;
+use tonic::Code;
 
-pub const TIMEOUT: Duration = Duration::from_secs(60);
 pub const MEMO: &str = "Sent using Somm Orchestrator";
+/// Cosmos SDK error code for a transaction signed with the wrong account sequence
+const WRONG_SEQUENCE_CODE: u32 = 32;
+const MAX_SEQUENCE_ATTEMPTS: u32 = 3;
+/// Cosmos SDK error code for a transaction rejected because the mempool is full. The transaction
+/// never reached a block, so sending it again can't submit it twice. Other codes, such as running
+/// out of gas (11), are returned for transactions that were executed and charged a fee.
+const MEMPOOL_FULL_CODE: u32 = 20;
+
+/// Determines the fee paid for a transaction from its simulated gas usage
+#[derive(Clone, Debug)]
+pub struct GasSettings {
+    /// Fee paid per unit of gas, in `denom`
+    pub price: f64,
+    pub denom: String,
+    /// Multiplied by the simulated gas usage to get the transaction's gas limit
+    pub adjustment: f64,
+    /// Transactions with an estimated fee above this are rejected rather than sent
+    pub max_fee: Option<u64>,
+}
+
+/// Determines how a transaction is broadcast and how failures to get it committed are retried
+#[derive(Clone, Debug)]
+pub struct BroadcastSettings {
+    pub mode: BroadcastMode,
+    /// How long to wait for a broadcast transaction to be committed
+    pub timeout: Duration,
+    /// Number of times a transaction is retried after a retryable failure
+    pub retries: u32,
+    /// Delay before the first retry, doubled for each retry after it
+    pub backoff: Duration,
+}
+
+enum AttemptError {
+    Retryable(Error),
+    Fatal(Error),
+}
+
+lazy_static! {
+    /// The next account sequence to sign with for each address that has sent a transaction
+    static ref SEQUENCES: Mutex<HashMap<String, u64>> = Mutex::new(HashMap::new());
+}
 
 pub async fn send_cork(
     contact: &Contact,
     cork: Cork,
     delegate_address: String,
     delegate_key: &CosmosPrivateKey,
-    fee: Coin,
-) -> Result<TxResponse, CosmosGrpcError> {
+    gas: &GasSettings,
+    broadcast: &BroadcastSettings,
+) -> Result<TxResponse, Error> {
     let msg = MsgSubmitCorkRequest {
         cork: Some(cork),
         signer: delegate_address,
     };
     let msg = Msg::new("/cork.v1.MsgSubmitCorkRequest", msg);
-    __send_messages(contact, delegate_key, fee, vec![msg]).await
+    __send_messages(contact, delegate_key, gas, broadcast, vec![msg]).await
+}
+
+pub async fn send_corks(
+    contact: &Contact,
+    corks: Vec<Cork>,
+    delegate_address: String,
+    delegate_key: &CosmosPrivateKey,
+    gas: &GasSettings,
+    broadcast: &BroadcastSettings,
+) -> Result<TxResponse, Error> {
+    let messages = corks
+        .into_iter()
+        .map(|cork| {
+            let msg = MsgSubmitCorkRequest {
+                cork: Some(cork),
+                signer: delegate_address.clone(),
+            };
+            Msg::new("/cork.v1.MsgSubmitCorkRequest", msg)
+        })
+        .collect();
+    __send_messages(contact, delegate_key, gas, broadcast, messages).await
 }
 
 pub async fn schedule_cork(
@@ -34,40 +110,457 @@ pub async fn schedule_cork(
     cork: Cork,
     delegate_address: String,
     delegate_key: &CosmosPrivateKey,
-    fee: Coin,
+    gas: &GasSettings,
+    broadcast: &BroadcastSettings,
     block_height: u64,
-) -> Result<TxResponse, CosmosGrpcError> {
+) -> Result<TxResponse, Error> {
     let msg = MsgScheduleCorkRequest {
         cork: Some(cork),
         signer: delegate_address,
         block_height,
     };
     let msg = Msg::new("/cork.v1.MsgScheduleCorkRequest", msg);
-    __send_messages(contact, delegate_key, fee, vec![msg]).await
+    __send_messages(contact, delegate_key, gas, broadcast, vec![msg]).await
 }
 
 async fn __send_messages(
     contact: &Contact,
     cosmos_key: &CosmosPrivateKey,
-    fee: Coin,
+    gas: &GasSettings,
+    broadcast: &BroadcastSettings,
     messages: Vec<Msg>,
-) -> Result<TxResponse, CosmosGrpcError> {
-    let cosmos_address = cosmos_key.to_address(&contact.get_prefix())?;
+) -> Result<TxResponse, Error> {
+    let mut backoff = broadcast.backoff;
+    let mut attempt = 1;
+    loop {
+        let timer = metrics::BROADCAST_LATENCY.start_timer();
+        let result = send_attempt(contact, cosmos_key, gas, broadcast, &messages, attempt).await;
+        timer.observe_duration();
+        match result {
+            Ok(response) => return Ok(response),
+            Err(AttemptError::Retryable(err)) if attempt <= broadcast.retries => {
+                warn!(
+                    "transaction attempt {} failed, retrying in {:?}: {}",
+                    attempt, backoff, err
+                );
+                tokio::time::sleep(backoff).await;
+                backoff *= 2;
+                attempt += 1;
+            }
+            Err(AttemptError::Retryable(err)) | Err(AttemptError::Fatal(err)) => return Err(err),
+        }
+    }
+}
+
+/// Signs and broadcasts the messages, then waits for the transaction to be committed
+async fn send_attempt(
+    contact: &Contact,
+    cosmos_key: &CosmosPrivateKey,
+    gas: &GasSettings,
+    broadcast: &BroadcastSettings,
+    messages: &[Msg],
+    attempt: u32,
+) -> Result<TxResponse, AttemptError> {
+    let (response, fee_amount) =
+        broadcast_messages(contact, cosmos_key, gas, broadcast.mode, messages).await?;
+    info!(
+        "transaction attempt {} broadcast with tx hash {}",
+        attempt, response.txhash
+    );
+    // Rejected before reaching a block, such as by the mempool checks
+    if response.code != 0 && response.height == 0 {
+        return Err(classify_response(&response));
+    }
+
+    // In block mode the response is only returned once the transaction has been committed
+    let response = if broadcast.mode == BroadcastMode::Block {
+        response
+    } else {
+        let txhash = response.txhash.clone();
+        match contact.wait_for_tx(response, broadcast.timeout).await {
+            Ok(response) => response,
+            // The transaction may have been committed just after the wait timed out, or may still
+            // be in the mempool, so it is looked up rather than sent again
+            Err(CosmosGrpcError::TransactionFailed { .. }) => {
+                warn!(
+                    "transaction {} not committed within {:?}, looking it up",
+                    txhash, broadcast.timeout
+                );
+                let found = get_tx(&txhash).await.map_err(AttemptError::Fatal)?;
+                timed_out(&txhash, found)?
+            }
+            Err(err) => return Err(classify_error(err)),
+        }
+    };
+
+    // Committed transactions are charged their fee whether or not they succeed
+    metrics::FEES_PAID
+        .with_label_values(&[&gas.denom])
+        .inc_by(fee_amount);
+    committed(response)
+}
+
+fn committed(response: TxResponse) -> Result<TxResponse, AttemptError> {
+    if response.code != 0 {
+        return Err(classify_response(&response));
+    }
+
+    Ok(response)
+}
+
+/// The committed transaction, if a lookup of its hash after the wait timed out found it. One that
+/// still can't be found isn't retried, since it may yet be committed and sending it again would
+/// submit its messages twice.
+fn timed_out(txhash: &str, found: Option<TxResponse>) -> Result<TxResponse, AttemptError> {
+    match found {
+        Some(response) => Ok(response),
+        None => Err(AttemptError::Fatal(
+            ErrorKind::GrpcError
+                .context(format!(
+                    "transaction {} was not committed before the timeout and may still be pending",
+                    txhash
+                ))
+                .into(),
+        )),
+    }
+}
+
+/// Looks up a committed transaction by hash, returning None if the node doesn't know of it
+async fn get_tx(txhash: &str) -> Result<Option<TxResponse>, Error> {
+    let mut client = TxServiceClient::new(connections::cosmos_channel().await?);
+    match client
+        .get_tx(GetTxRequest {
+            hash: txhash.to_string(),
+        })
+        .await
+    {
+        Ok(response) => Ok(response.into_inner().tx_response),
+        Err(status) if status.code() == Code::NotFound => Ok(None),
+        Err(status) => Err(status.into()),
+    }
+}
+
+/// Signs and broadcasts the messages, returning the node's response along with the fee set on the
+/// transaction, as an amount of `gas.denom`
+async fn broadcast_messages(
+    contact: &Contact,
+    cosmos_key: &CosmosPrivateKey,
+    gas: &GasSettings,
+    mode: BroadcastMode,
+    messages: &[Msg],
+) -> Result<(TxResponse, u64), AttemptError> {
+    let mut attempt = 1;
+    loop {
+        // Holding the lock until the transaction has been broadcast serializes signing, so
+        // concurrent transactions from the same key are given distinct sequences
+        let mut sequences = SEQUENCES.lock().await;
+
+        let cosmos_address = cosmos_key
+            .to_address(&contact.get_prefix())
+            .map_err(|err| AttemptError::Fatal(err.into()))?;
+        let address = cosmos_address.to_string();
+        let simulation_fee = Fee {
+            amount: vec![],
+            gas_limit: 0,
+            granter: None,
+            payer: None,
+        };
+
+        let mut args = contact
+            .get_message_args(cosmos_address, simulation_fee)
+            .await
+            .map_err(classify_error)?;
+        let sequence = next_sequence(&sequences, &address, args.sequence);
+        args.sequence = sequence;
+
+        // Simulation checks the sequence too, so a mismatch here is resynced in the same way
+        let simulation_bytes = cosmos_key
+            .sign_std_msg(messages, args.clone(), MEMO)
+            .map_err(|err| AttemptError::Fatal(err.into()))?;
+        let fee_amount = match estimate_fee(simulation_bytes, gas).await {
+            Ok((fee, amount)) => {
+                args.fee = fee;
+                amount
+            }
+            Err(err)
+                if is_sequence_mismatch(0, &err.to_string()) && attempt < MAX_SEQUENCE_ATTEMPTS =>
+            {
+                warn!(
+                    "account sequence {} rejected in simulation for {}, resyncing: {}",
+                    sequence, address, err
+                );
+                sequences.remove(&address);
+                attempt += 1;
+                continue;
+            }
+            Err(err) => return Err(AttemptError::Fatal(err)),
+        };
+
+        let msg_bytes = cosmos_key
+            .sign_std_msg(messages, args, MEMO)
+            .map_err(|err| AttemptError::Fatal(err.into()))?;
+        match contact.send_transaction(msg_bytes, mode).await {
+            Ok(response) if response.code == 0 => {
+                sequences.insert(address, sequence + 1);
+                return Ok((response, fee_amount));
+            }
+            Ok(response)
+                if is_sequence_mismatch(response.code, &response.raw_log)
+                    && attempt < MAX_SEQUENCE_ATTEMPTS =>
+            {
+                warn!(
+                    "account sequence {} rejected for {}, resyncing: {}",
+                    sequence, address, response.raw_log
+                );
+            }
+            Ok(response) => return Ok((response, fee_amount)),
+            Err(err)
+                if is_sequence_mismatch(0, &err.to_string()) && attempt < MAX_SEQUENCE_ATTEMPTS =>
+            {
+                warn!(
+                    "account sequence {} rejected for {}, resyncing: {}",
+                    sequence, address, err
+                );
+            }
+            Err(err) => {
+                sequences.remove(&address);
+                return Err(classify_error(err));
+            }
+        }
+
+        // Forget the local sequence so the next attempt uses the chain's
+        sequences.remove(&address);
+        attempt += 1;
+    }
+}
+
+/// The sequence to sign the address's next transaction with. The chain doesn't count transactions
+/// still in the mempool, so the locally tracked sequence may be ahead of the chain's.
+fn next_sequence(sequences: &HashMap<String, u64>, address: &str, chain_sequence: u64) -> u64 {
+    match sequences.get(address) {
+        Some(sequence) => max(chain_sequence, *sequence),
+        None => chain_sequence,
+    }
+}
+
+fn is_sequence_mismatch(code: u32, log: &str) -> bool {
+    code == WRONG_SEQUENCE_CODE || log.contains("account sequence mismatch")
+}
+
+/// Connection failures and unavailable nodes are worth retrying. Anything else would fail again
+/// in the same way.
+fn classify_error(err: CosmosGrpcError) -> AttemptError {
+    let retryable = match &err {
+        CosmosGrpcError::ConnectionError { .. } => true,
+        CosmosGrpcError::RequestError { error } => matches!(
+            error.code(),
+            Code::Unavailable | Code::DeadlineExceeded | Code::ResourceExhausted
+        ),
+        _ => false,
+    };
+
+    if retryable {
+        AttemptError::Retryable(err.into())
+    } else {
+        AttemptError::Fatal(err.into())
+    }
+}
+
+fn classify_response(response: &TxResponse) -> AttemptError {
+    let err = ErrorKind::GrpcError
+        .context(format!(
+            "transaction {} failed with code {}: {}",
+            response.txhash, response.code, response.raw_log
+        ))
+        .into();
+
+    if response.code == MEMPOOL_FULL_CODE || is_sequence_mismatch(response.code, &response.raw_log)
+    {
+        AttemptError::Retryable(err)
+    } else {
+        AttemptError::Fatal(err)
+    }
+}
+
+/// Simulates the signed transaction to get its gas usage, and computes a fee for it. The fee is
+/// also returned as an amount of `gas.denom`.
+async fn estimate_fee(tx_bytes: Vec<u8>, gas: &GasSettings) -> Result<(Fee, u64), Error> {
+    fee_for_gas(simulate(tx_bytes).await?, gas)
+}
+
+/// The fee for a transaction that used `gas_used` in simulation, along with its amount
+fn fee_for_gas(gas_used: u64, gas: &GasSettings) -> Result<(Fee, u64), Error> {
+    let gas_limit = (gas_used as f64 * gas.adjustment).ceil() as u64;
+    let amount = (gas_limit as f64 * gas.price).ceil() as u64;
+    debug!(
+        "simulated gas used {}, gas limit {}, fee {}{}",
+        gas_used, gas_limit, amount, gas.denom
+    );
+
+    if let Some(max_fee) = gas.max_fee {
+        if amount > max_fee {
+            return Err(ErrorKind::ClientError
+                .context(format!(
+                    "estimated fee {}{} exceeds max fee {}{}",
+                    amount, gas.denom, max_fee, gas.denom
+                ))
+                .into());
+        }
+    }
 
     let fee = Fee {
-        amount: vec![fee],
-        gas_limit: 500_000_000u64 * (messages.len() as u64),
+        amount: vec![Coin {
+            amount: amount.into(),
+            denom: gas.denom.clone(),
+        }],
+        gas_limit,
         granter: None,
         payer: None,
     };
 
-    let args = contact.get_message_args(cosmos_address, fee).await?;
+    Ok((fee, amount))
+}
+
+async fn simulate(tx_bytes: Vec<u8>) -> Result<u64, Error> {
+    let decode_error = |err: prost::DecodeError| ErrorKind::ClientError.context(err);
+    let raw = TxRaw::decode(tx_bytes.as_slice()).map_err(decode_error)?;
+    let tx = Tx {
+        body: Some(TxBody::decode(raw.body_bytes.as_slice()).map_err(decode_error)?),
+        auth_info: Some(AuthInfo::decode(raw.auth_info_bytes.as_slice()).map_err(decode_error)?),
+        signatures: raw.signatures,
+    };
+
+    let mut client = TxServiceClient::new(connections::cosmos_channel().await?);
+    let response = client
+        .simulate(SimulateRequest { tx: Some(tx) })
+        .await?
+        .into_inner();
+    match response.gas_info {
+        Some(info) => Ok(info.gas_used),
+        None => Err(ErrorKind::GrpcError
+            .context("tx simulation returned no gas info")
+            .into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tonic::Status;
+
+    fn is_retryable(err: AttemptError) -> bool {
+        matches!(err, AttemptError::Retryable(_))
+    }
+
+    fn response(code: u32, raw_log: &str) -> TxResponse {
+        TxResponse {
+            txhash: "ABCD".to_string(),
+            code,
+            raw_log: raw_log.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn unreachable_nodes_are_retried() {
+        for code in [
+            Code::Unavailable,
+            Code::DeadlineExceeded,
+            Code::ResourceExhausted,
+        ]
+        .iter()
+        {
+            let err = CosmosGrpcError::RequestError {
+                error: Status::new(*code, "node unreachable"),
+            };
+            assert!(is_retryable(classify_error(err)), "{:?}", code);
+        }
+
+        let err = CosmosGrpcError::RequestError {
+            error: Status::new(Code::InvalidArgument, "bad request"),
+        };
+        assert!(!is_retryable(classify_error(err)));
+    }
+
+    #[test]
+    fn only_transactions_that_were_not_executed_are_retried() {
+        assert!(is_retryable(classify_response(&response(
+            20,
+            "mempool is full"
+        ))));
+        assert!(is_retryable(classify_response(&response(
+            32,
+            "account sequence mismatch, expected 5, got 4"
+        ))));
+
+        assert!(!is_retryable(classify_response(&response(
+            11,
+            "out of gas"
+        ))));
+        assert!(!is_retryable(classify_response(&response(
+            5,
+            "insufficient funds"
+        ))));
+    }
+
+    #[test]
+    fn timed_out_transactions_are_not_sent_again() {
+        assert!(timed_out("ABCD", Some(response(0, "")))
+            .and_then(committed)
+            .is_ok());
+        assert!(!is_retryable(
+            timed_out("ABCD", Some(response(11, "out of gas")))
+                .and_then(committed)
+                .unwrap_err()
+        ));
+        assert!(!is_retryable(timed_out("ABCD", None).unwrap_err()));
+    }
+
+    #[test]
+    fn local_sequence_is_used_while_ahead_of_chain() {
+        let mut sequences = HashMap::new();
+        assert_eq!(next_sequence(&sequences, "somm1a", 7), 7);
+
+        sequences.insert("somm1a".to_string(), 9);
+        assert_eq!(next_sequence(&sequences, "somm1a", 7), 9);
+        assert_eq!(next_sequence(&sequences, "somm1a", 12), 12);
+        assert_eq!(next_sequence(&sequences, "somm1b", 3), 3);
+    }
+
+    #[test]
+    fn sequence_mismatches_are_detected_by_code_or_log() {
+        assert!(is_sequence_mismatch(32, ""));
+        assert!(is_sequence_mismatch(
+            0,
+            "account sequence mismatch, expected 5, got 4: incorrect account sequence"
+        ));
+        assert!(!is_sequence_mismatch(5, "insufficient funds"));
+    }
+
+    fn gas_settings(max_fee: Option<u64>) -> GasSettings {
+        GasSettings {
+            price: 0.025,
+            denom: "usomm".to_string(),
+            adjustment: 1.2,
+            max_fee,
+        }
+    }
+
+    #[test]
+    fn fee_covers_adjusted_gas() {
+        let (fee, amount) = fee_for_gas(100_001, &gas_settings(None)).unwrap();
 
-    let msg_bytes = cosmos_key.sign_std_msg(&messages, args, MEMO)?;
+        // 100_001 * 1.2 rounded up, then * 0.025 rounded up
+        assert_eq!(fee.gas_limit, 120_002);
+        assert_eq!(amount, 3001);
+        assert_eq!(fee.amount[0].denom, "usomm");
+    }
 
-    let response = contact
-        .send_transaction(msg_bytes, BroadcastMode::Sync)
-        .await?;
+    #[test]
+    fn fee_above_maximum_is_rejected() {
+        assert!(fee_for_gas(100_000, &gas_settings(Some(3000))).is_ok());
 
-    contact.wait_for_tx(response, TIMEOUT).await
+        let err = fee_for_gas(100_000, &gas_settings(Some(2999))).unwrap_err();
+        assert_eq!(*err.kind(), ErrorKind::ClientError);
+    }
 }
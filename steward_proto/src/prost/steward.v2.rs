@@ -5,7 +5,7 @@ pub struct AaveV2Stablecoin {
     /// The function you wish to execute on the target cellar
     #[prost(
         oneof = "aave_v2_stablecoin::Function",
-        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12"
+        tags = "1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19"
     )]
     pub function: ::core::option::Option<aave_v2_stablecoin::Function>,
 }
@@ -141,6 +141,78 @@ pub mod aave_v2_stablecoin {
     /// Represents function `sendFees()`
     #[derive(Clone, PartialEq, ::prost::Message)]
     pub struct SendFees {}
+    ///
+    /// Sweep tokens sent to the cellar by mistake. Cannot sweep protected assets such as the cellar asset.
+    ///
+    /// Represents function `sweep(address token, address to)`
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Sweep {
+        /// Address of the token to sweep
+        #[prost(string, tag = "1")]
+        pub token: ::prost::alloc::string::String,
+        /// Address to send the swept tokens to. Cannot be the zero address.
+        #[prost(string, tag = "2")]
+        pub to: ::prost::alloc::string::String,
+    }
+    ///
+    /// Trust or distrust a position the cellar may rebalance into.
+    ///
+    /// Represents function `setTrust(address position, bool trust)`
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SetTrust {
+        /// Address of the position token
+        #[prost(string, tag = "1")]
+        pub position: ::prost::alloc::string::String,
+        /// Whether to trust the position
+        #[prost(bool, tag = "2")]
+        pub trust: bool,
+    }
+    ///
+    /// Set the Cosmos address platform fees are distributed to.
+    ///
+    /// Represents function `setFeesDistributor(bytes32 newFeesDistributor)`
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct SetFeesDistributor {
+        /// The new fees distributor as a hex encoded 32 byte word, with or without a 0x prefix
+        #[prost(string, tag = "1")]
+        pub new_fees_distributor: ::prost::alloc::string::String,
+    }
+    ///
+    /// Shut down the cellar, preventing deposits and rebalances.
+    ///
+    /// Represents function `initiateShutdown(bool emptyPosition)`
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct InitiateShutdown {
+        /// Whether to pull all assets from the current Aave lending position in the process
+        #[prost(bool, tag = "1")]
+        pub empty_position: bool,
+    }
+    ///
+    /// Restart the cellar after a shutdown.
+    ///
+    /// Represents function `liftShutdown()`
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct LiftShutdown {}
+    ///
+    /// Transfer ownership of the cellar.
+    ///
+    /// Represents function `transferOwnership(address newOwner)`
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TransferOwnership {
+        /// Address of the new owner. Cannot be the zero address.
+        #[prost(string, tag = "1")]
+        pub new_owner: ::prost::alloc::string::String,
+    }
+    ///
+    /// Execute several function calls on the cellar atomically, in order, in a single transaction.
+    ///
+    /// Represents function `multicall(bytes[] data)`
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Multicall {
+        /// The function calls to execute, in order. Each must set a function other than multicall.
+        #[prost(message, repeated, tag = "1")]
+        pub calls: ::prost::alloc::vec::Vec<super::AaveV2Stablecoin>,
+    }
     /// The function you wish to execute on the target cellar
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Function {
@@ -180,6 +252,27 @@ pub mod aave_v2_stablecoin {
         /// Represents function `transferFees()`
         #[prost(message, tag = "12")]
         SendFees(SendFees),
+        /// Represents function `sweep(address token, address to)`
+        #[prost(message, tag = "13")]
+        Sweep(Sweep),
+        /// Represents function `setTrust(address position, bool trust)`
+        #[prost(message, tag = "14")]
+        SetTrust(SetTrust),
+        /// Represents function `setFeesDistributor(bytes32 newFeesDistributor)`
+        #[prost(message, tag = "15")]
+        SetFeesDistributor(SetFeesDistributor),
+        /// Represents function `initiateShutdown(bool emptyPosition)`
+        #[prost(message, tag = "16")]
+        InitiateShutdown(InitiateShutdown),
+        /// Represents function `liftShutdown()`
+        #[prost(message, tag = "17")]
+        LiftShutdown(LiftShutdown),
+        /// Represents function `transferOwnership(address newOwner)`
+        #[prost(message, tag = "18")]
+        TransferOwnership(TransferOwnership),
+        /// Represents function `multicall(bytes[] data)`
+        #[prost(message, tag = "19")]
+        Multicall(Multicall),
     }
 }
 ///
@@ -189,6 +282,9 @@ pub struct SubmitRequest {
     /// The ID (currently simply an Ethereum address) of the target Cellar
     #[prost(string, tag = "1")]
     pub cellar_id: ::prost::alloc::string::String,
+    /// An optional client chosen key identifying the request. If the submission journal is enabled and a request with the same key has already been submitted successfully, its original response is returned instead of submitting again. Keys are scoped to the client identity, and reusing a key for a different request is rejected. A key whose request failed before its cork was sent may be retried, but a key whose request failed while its cork was being sent is rejected with FAILED_PRECONDITION, since the cork may have reached the chain. Only supported by Submit, SubmitBatch and ScheduleCork reject requests that set it.
+    #[prost(string, tag = "3")]
+    pub idempotency_key: ::prost::alloc::string::String,
     /// The data from which the desired contract function will be encoded
     #[prost(oneof = "submit_request::CallData", tags = "2")]
     pub call_data: ::core::option::Option<submit_request::CallData>,
@@ -202,8 +298,378 @@ pub mod submit_request {
         AaveV2Stablecoin(super::AaveV2Stablecoin),
     }
 }
+///
+/// Represents the receipt of a submitted function call
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitResponse {
+    /// The hash of the transaction containing the cork
+    #[prost(string, tag = "1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    /// The block height at which the transaction was committed
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    /// The gas used by the transaction
+    #[prost(uint64, tag = "3")]
+    pub gas_used: u64,
+    /// The ID of the target Cellar
+    #[prost(string, tag = "4")]
+    pub cellar_id: ::prost::alloc::string::String,
+    /// The hex encoded Keccak-256 hash of the target Cellar address and encoded contract call. This is the same hash the cork module uses to identify a cork.
+    #[prost(string, tag = "5")]
+    pub cork_id: ::prost::alloc::string::String,
+}
+///
+/// Represents multiple function calls, possibly on different Cellars, to be submitted together in a single transaction
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitBatchRequest {
+    /// The function calls to submit. Each is validated independently. The cork module keeps one cork per validator per Cellar, so calls for a Cellar that appears more than once are all rejected. Use a multicall to make several calls on one Cellar.
+    #[prost(message, repeated, tag = "1")]
+    pub requests: ::prost::alloc::vec::Vec<SubmitRequest>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitBatchResponse {
+    /// The result of each function call, in the same order as the requests
+    #[prost(message, repeated, tag = "1")]
+    pub results: ::prost::alloc::vec::Vec<SubmitBatchResult>,
+}
+///
+/// Represents the outcome of a single function call in a batch
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmitBatchResult {
+    /// The ID of the target Cellar
+    #[prost(string, tag = "1")]
+    pub cellar_id: ::prost::alloc::string::String,
+    /// Whether the function call was included in a successfully sent transaction
+    #[prost(bool, tag = "2")]
+    pub success: bool,
+    /// The reason the function call was rejected or failed to send, if unsuccessful
+    #[prost(string, tag = "3")]
+    pub error: ::prost::alloc::string::String,
+    /// The hash of the transaction containing the cork, if it was sent
+    #[prost(string, tag = "4")]
+    pub tx_hash: ::prost::alloc::string::String,
+    /// The hex encoded Keccak-256 hash of the target Cellar address and encoded contract call, if the function call could be encoded. This is the same hash the cork module uses to identify a cork.
+    #[prost(string, tag = "5")]
+    pub cork_id: ::prost::alloc::string::String,
+}
+///
+/// Represents a single function call on a particular Cellar to be executed at a future block height
 #[derive(Clone, PartialEq, ::prost::Message)]
-pub struct SubmitResponse {}
+pub struct ScheduleCorkRequest {
+    /// The function call to schedule
+    #[prost(message, optional, tag = "1")]
+    pub request: ::core::option::Option<SubmitRequest>,
+    /// The block height at which the cork module should execute the function call
+    #[prost(uint64, tag = "2")]
+    pub block_height: u64,
+}
+///
+/// Represents the receipt of a scheduled function call
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduleCorkResponse {
+    /// The hash of the transaction containing the scheduled cork
+    #[prost(string, tag = "1")]
+    pub tx_hash: ::prost::alloc::string::String,
+    /// The block height at which the transaction was committed
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    /// The gas used by the transaction
+    #[prost(uint64, tag = "3")]
+    pub gas_used: u64,
+    /// The ID of the target Cellar
+    #[prost(string, tag = "4")]
+    pub cellar_id: ::prost::alloc::string::String,
+    /// The hex encoded ID of the cork
+    #[prost(string, tag = "5")]
+    pub cork_id: ::prost::alloc::string::String,
+    /// The block height the cork is scheduled for
+    #[prost(uint64, tag = "6")]
+    pub block_height: u64,
+}
+///
+/// Represents a request for the status of a cork. The cork is identified either by its ID, or by the target Cellar and encoded contract call from which the ID is computed.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCorkStatusRequest {
+    /// The ID of the target Cellar
+    #[prost(string, tag = "1")]
+    pub cellar_id: ::prost::alloc::string::String,
+    /// The ABI encoded contract call
+    #[prost(bytes = "vec", tag = "2")]
+    pub encoded_contract_call: ::prost::alloc::vec::Vec<u8>,
+    /// The hex encoded cork ID as returned in SubmitResponse. Takes precedence over cellar_id and encoded_contract_call.
+    #[prost(string, tag = "3")]
+    pub cork_id: ::prost::alloc::string::String,
+}
+///
+/// Represents the progress of a cork through the cork module vote and the Gravity bridge. Whether this Steward voted for the cork is not reported, since the cork module does not record which validator submitted a cork.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCorkStatusResponse {
+    /// The hex encoded cork ID
+    #[prost(string, tag = "1")]
+    pub cork_id: ::prost::alloc::string::String,
+    /// The number of votes for the cork submitted in the current vote period. The cork module does not record which validators cast these votes.
+    #[prost(uint64, tag = "2")]
+    pub submitted_votes: u64,
+    /// The block height the cork is scheduled for, or 0 if it has not been scheduled
+    #[prost(uint64, tag = "3")]
+    pub scheduled_height: u64,
+    /// The consensus power of the validators with a scheduled vote recorded for the cork
+    #[prost(uint64, tag = "5")]
+    pub vote_power: u64,
+    /// The consensus power of the bonded validator set
+    #[prost(uint64, tag = "6")]
+    pub total_power: u64,
+    /// Whether the cork's contract call has been executed by the Gravity contract. A pending cork is compared against the nonce of its own contract call, not earlier calls with the same ID.
+    #[prost(bool, tag = "7")]
+    pub executed: bool,
+}
+///
+/// Represents a query of the submission journal. Unset filters match every submission.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSubmissionHistoryRequest {
+    /// Only list submissions for this Cellar
+    #[prost(string, tag = "1")]
+    pub cellar_id: ::prost::alloc::string::String,
+    /// Only list submissions received at or after this Unix timestamp, in seconds
+    #[prost(int64, tag = "2")]
+    pub start_time: i64,
+    /// Only list submissions received before this Unix timestamp, in seconds
+    #[prost(int64, tag = "3")]
+    pub end_time: i64,
+    /// Only list submissions with this status, one of pending, sending, succeeded, failed or unknown
+    #[prost(string, tag = "4")]
+    pub status: ::prost::alloc::string::String,
+    /// The maximum number of submissions to list. Defaults to 100.
+    #[prost(uint32, tag = "5")]
+    pub limit: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetSubmissionHistoryResponse {
+    /// The matching submissions, most recent first
+    #[prost(message, repeated, tag = "1")]
+    pub submissions: ::prost::alloc::vec::Vec<SubmissionRecord>,
+}
+///
+/// Represents a single journaled submission and its outcome
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SubmissionRecord {
+    /// The journal's ID for the submission
+    #[prost(uint64, tag = "1")]
+    pub id: u64,
+    /// The ID of the target Cellar
+    #[prost(string, tag = "2")]
+    pub cellar_id: ::prost::alloc::string::String,
+    /// The name of the contract function called, if the request could be encoded
+    #[prost(string, tag = "3")]
+    pub function: ::prost::alloc::string::String,
+    /// The function arguments as a JSON object keyed by argument name
+    #[prost(string, tag = "4")]
+    pub arguments: ::prost::alloc::string::String,
+    /// The hex encoded ID of the cork
+    #[prost(string, tag = "5")]
+    pub cork_id: ::prost::alloc::string::String,
+    /// The hash of the transaction containing the cork, if it was sent
+    #[prost(string, tag = "6")]
+    pub tx_hash: ::prost::alloc::string::String,
+    /// The block height at which the transaction was committed
+    #[prost(uint64, tag = "7")]
+    pub height: u64,
+    /// One of pending, sending, succeeded, failed or unknown. A submission is unknown if sending its cork failed or was interrupted, in which case the cork may still have reached the chain.
+    #[prost(string, tag = "8")]
+    pub status: ::prost::alloc::string::String,
+    /// The reason the submission failed, if it did
+    #[prost(string, tag = "9")]
+    pub error: ::prost::alloc::string::String,
+    /// The Unix timestamp, in seconds, at which the request was received
+    #[prost(int64, tag = "10")]
+    pub created_at: i64,
+    /// The Unix timestamp, in seconds, at which the outcome was recorded
+    #[prost(int64, tag = "11")]
+    pub updated_at: i64,
+    /// The idempotency key provided with the request, if any
+    #[prost(string, tag = "12")]
+    pub idempotency_key: ::prost::alloc::string::String,
+}
+///
+/// Represents a single function call on a particular Cellar to validate and encode without submitting it
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PreviewRequest {
+    /// The function call to preview
+    #[prost(message, optional, tag = "1")]
+    pub request: ::core::option::Option<SubmitRequest>,
+}
+///
+/// Represents the cork that would be submitted for a function call
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PreviewResponse {
+    /// The cork that would be submitted
+    #[prost(message, optional, tag = "1")]
+    pub cork: ::core::option::Option<Cork>,
+    /// The hex encoded ID of the cork
+    #[prost(string, tag = "2")]
+    pub cork_id: ::prost::alloc::string::String,
+    /// The hex encoded ABI calldata of the contract call, the same bytes as in the cork
+    #[prost(string, tag = "3")]
+    pub encoded_call: ::prost::alloc::string::String,
+    /// The hex encoded four byte function selector at the start of the calldata
+    #[prost(string, tag = "4")]
+    pub selector: ::prost::alloc::string::String,
+    /// The name of the contract function as it appears in the Cellar ABI
+    #[prost(string, tag = "5")]
+    pub function: ::prost::alloc::string::String,
+    /// The signature of the contract function, for example setDepositLimit(uint256)
+    #[prost(string, tag = "6")]
+    pub signature: ::prost::alloc::string::String,
+    /// The function arguments as a JSON object keyed by argument name. Integers are decimal strings.
+    #[prost(string, tag = "7")]
+    pub arguments: ::prost::alloc::string::String,
+}
+///
+/// Represents a contract call on a Cellar as it is submitted to the cork module
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct Cork {
+    /// The ABI encoded contract call
+    #[prost(bytes = "vec", tag = "1")]
+    pub encoded_contract_call: ::prost::alloc::vec::Vec<u8>,
+    /// The address of the target Cellar
+    #[prost(string, tag = "2")]
+    pub target_contract_address: ::prost::alloc::string::String,
+}
+///
+/// Represents an encoded contract call to decode, for example from a cork submitted by another validator
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DecodeRequest {
+    /// The ABI encoded contract call
+    #[prost(bytes = "vec", tag = "1")]
+    pub encoded_contract_call: ::prost::alloc::vec::Vec<u8>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DecodeResponse {
+    /// The decoded contract call
+    #[prost(message, optional, tag = "1")]
+    pub call: ::core::option::Option<DecodedCall>,
+}
+///
+/// Represents a contract call decoded against the ABI of one of the Cellar families Steward supports
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct DecodedCall {
+    /// The name of the Cellar family whose ABI matched the call, for example aave_v2_stablecoin
+    #[prost(string, tag = "1")]
+    pub cellar: ::prost::alloc::string::String,
+    /// The name of the contract function as it appears in the Cellar ABI
+    #[prost(string, tag = "2")]
+    pub function: ::prost::alloc::string::String,
+    /// The signature of the contract function, for example setDepositLimit(uint256)
+    #[prost(string, tag = "3")]
+    pub signature: ::prost::alloc::string::String,
+    /// The hex encoded four byte function selector at the start of the calldata
+    #[prost(string, tag = "4")]
+    pub selector: ::prost::alloc::string::String,
+    /// The function arguments as a JSON object keyed by argument name. Integers are decimal strings.
+    #[prost(string, tag = "5")]
+    pub arguments: ::prost::alloc::string::String,
+    /// For a multicall, the calls it wraps, decoded, in the order they are executed
+    #[prost(message, repeated, tag = "6")]
+    pub calls: ::prost::alloc::vec::Vec<DecodedCall>,
+}
+///
+/// Represents a request for the state of a Cellar
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCellarStateRequest {
+    /// The ID (currently simply an Ethereum address) of the Cellar
+    #[prost(string, tag = "1")]
+    pub cellar_id: ::prost::alloc::string::String,
+}
+///
+/// Represents the view state of an AaveV2StablecoinCellar, read at a single Ethereum block. Amounts are decimal strings in the smallest unit of their token, and addresses are hex strings.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetCellarStateResponse {
+    /// The ID of the Cellar
+    #[prost(string, tag = "1")]
+    pub cellar_id: ::prost::alloc::string::String,
+    /// The name of the Cellar share token
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    /// The symbol of the Cellar share token
+    #[prost(string, tag = "3")]
+    pub symbol: ::prost::alloc::string::String,
+    /// The address of the owner of the Cellar
+    #[prost(string, tag = "4")]
+    pub owner: ::prost::alloc::string::String,
+    /// The address of the asset the Cellar is currently holding
+    #[prost(string, tag = "5")]
+    pub asset: ::prost::alloc::string::String,
+    /// The number of decimals of the asset
+    #[prost(uint32, tag = "6")]
+    pub asset_decimals: u32,
+    /// The address of the Aave aToken of the asset
+    #[prost(string, tag = "7")]
+    pub asset_a_token: ::prost::alloc::string::String,
+    /// The total assets under management, including those deposited into Aave
+    #[prost(string, tag = "8")]
+    pub total_assets: ::prost::alloc::string::String,
+    /// The assets under management, less yield that is still locked
+    #[prost(string, tag = "9")]
+    pub total_holdings: ::prost::alloc::string::String,
+    /// The yield locked until it vests over the accrual period
+    #[prost(string, tag = "10")]
+    pub total_locked: ::prost::alloc::string::String,
+    /// The assets deposited into Aave as of the last accrual
+    #[prost(string, tag = "11")]
+    pub total_balance: ::prost::alloc::string::String,
+    /// The total supply of Cellar shares
+    #[prost(string, tag = "12")]
+    pub total_supply: ::prost::alloc::string::String,
+    /// The maximum yield that can be locked by an accrual
+    #[prost(string, tag = "13")]
+    pub max_locked: ::prost::alloc::string::String,
+    /// The maximum assets a single user can deposit
+    #[prost(string, tag = "14")]
+    pub deposit_limit: ::prost::alloc::string::String,
+    /// The maximum assets the Cellar can hold
+    #[prost(string, tag = "15")]
+    pub liquidity_limit: ::prost::alloc::string::String,
+    /// Whether the Cellar has been shut down
+    #[prost(bool, tag = "16")]
+    pub is_shutdown: bool,
+    /// The Unix timestamp, in seconds, of the last accrual
+    #[prost(uint64, tag = "17")]
+    pub last_accrual: u64,
+    /// The duration of the accrual period, in seconds
+    #[prost(uint32, tag = "18")]
+    pub accrual_period: u32,
+    /// The platform fee taken on assets under management, as an 18 decimal fixed point fraction
+    #[prost(uint64, tag = "19")]
+    pub platform_fee: u64,
+    /// The performance fee taken on yield, as an 18 decimal fixed point fraction
+    #[prost(uint64, tag = "20")]
+    pub performance_fee: u64,
+    /// The hex encoded Cosmos address fees are distributed to, as a 32 byte word
+    #[prost(string, tag = "21")]
+    pub fees_distributor: ::prost::alloc::string::String,
+    /// The Ethereum block height the state was read at
+    #[prost(uint64, tag = "22")]
+    pub block_number: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAdaptersRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct GetAdaptersResponse {
+    /// The supported Cellar families, in the order calls are dispatched to them
+    #[prost(message, repeated, tag = "1")]
+    pub adapters: ::prost::alloc::vec::Vec<CellarAdapterInfo>,
+}
+///
+/// Represents a Cellar family supported by this Steward
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CellarAdapterInfo {
+    /// The name of the Cellar family, e.g. aave_v2_stablecoin
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// The signatures of the contract functions in the Cellar ABI
+    #[prost(string, repeated, tag = "2")]
+    pub functions: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
 #[doc = r" Generated client implementations."]
 pub mod contract_call_client {
     #![allow(unused_variables, dead_code, missing_docs)]
@@ -254,6 +720,100 @@ pub mod contract_call_client {
             let path = http::uri::PathAndQuery::from_static("/steward.v2.ContractCall/Submit");
             self.inner.unary(request.into_request(), path, codec).await
         }
+        #[doc = " Handles submission of multiple contract calls in a single transaction"]
+        pub async fn submit_batch(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubmitBatchRequest>,
+        ) -> Result<tonic::Response<super::SubmitBatchResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/steward.v2.ContractCall/SubmitBatch");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " Handles scheduling of a contract call at a future block height"]
+        pub async fn schedule_cork(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ScheduleCorkRequest>,
+        ) -> Result<tonic::Response<super::ScheduleCorkResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/steward.v2.ContractCall/ScheduleCork");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " Reports the vote and execution progress of a submitted cork"]
+        pub async fn get_cork_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetCorkStatusRequest>,
+        ) -> Result<tonic::Response<super::GetCorkStatusResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/steward.v2.ContractCall/GetCorkStatus");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " Lists past submissions recorded in the submission journal. Only Submit requests are journaled. With an authorization policy configured, only submissions made by the calling client are listed"]
+        pub async fn get_submission_history(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetSubmissionHistoryRequest>,
+        ) -> Result<tonic::Response<super::GetSubmissionHistoryResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/steward.v2.ContractCall/GetSubmissionHistory",
+            );
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " Validates and encodes a contract call exactly as Submit would, returning the cork that would be sent without sending it"]
+        pub async fn preview(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PreviewRequest>,
+        ) -> Result<tonic::Response<super::PreviewResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/steward.v2.ContractCall/Preview");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " Decodes the encoded contract call of a cork into the Cellar function it calls and its arguments"]
+        pub async fn decode(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DecodeRequest>,
+        ) -> Result<tonic::Response<super::DecodeResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/steward.v2.ContractCall/Decode");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
     }
     impl<T: Clone> Clone for ContractCallClient<T> {
         fn clone(&self) -> Self {
@@ -268,6 +828,86 @@ pub mod contract_call_client {
         }
     }
 }
+#[doc = r" Generated client implementations."]
+pub mod cellar_query_client {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = ""]
+    #[doc = " Service for reading the state of Cellar contracts from Ethereum"]
+    pub struct CellarQueryClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl CellarQueryClient<tonic::transport::Channel> {
+        #[doc = r" Attempt to create a new client by connecting to a given endpoint."]
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: std::convert::TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> CellarQueryClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::BoxBody>,
+        T::ResponseBody: Body + HttpBody + Send + 'static,
+        T::Error: Into<StdError>,
+        <T::ResponseBody as HttpBody>::Error: Into<StdError> + Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = tonic::client::Grpc::with_interceptor(inner, interceptor);
+            Self { inner }
+        }
+        #[doc = " Returns a snapshot of the view state of a Cellar"]
+        pub async fn get_cellar_state(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetCellarStateRequest>,
+        ) -> Result<tonic::Response<super::GetCellarStateResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path =
+                http::uri::PathAndQuery::from_static("/steward.v2.CellarQuery/GetCellarState");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+        #[doc = " Lists the Cellar families this Steward can encode and decode calls for"]
+        pub async fn get_adapters(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetAdaptersRequest>,
+        ) -> Result<tonic::Response<super::GetAdaptersResponse>, tonic::Status> {
+            self.inner.ready().await.map_err(|e| {
+                tonic::Status::new(
+                    tonic::Code::Unknown,
+                    format!("Service was not ready: {}", e.into()),
+                )
+            })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static("/steward.v2.CellarQuery/GetAdapters");
+            self.inner.unary(request.into_request(), path, codec).await
+        }
+    }
+    impl<T: Clone> Clone for CellarQueryClient<T> {
+        fn clone(&self) -> Self {
+            Self {
+                inner: self.inner.clone(),
+            }
+        }
+    }
+    impl<T> std::fmt::Debug for CellarQueryClient<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "CellarQueryClient {{ ... }}")
+        }
+    }
+}
 #[doc = r" Generated server implementations."]
 pub mod contract_call_server {
     #![allow(unused_variables, dead_code, missing_docs)]
@@ -280,6 +920,36 @@ pub mod contract_call_server {
             &self,
             request: tonic::Request<super::SubmitRequest>,
         ) -> Result<tonic::Response<super::SubmitResponse>, tonic::Status>;
+        #[doc = " Handles submission of multiple contract calls in a single transaction"]
+        async fn submit_batch(
+            &self,
+            request: tonic::Request<super::SubmitBatchRequest>,
+        ) -> Result<tonic::Response<super::SubmitBatchResponse>, tonic::Status>;
+        #[doc = " Handles scheduling of a contract call at a future block height"]
+        async fn schedule_cork(
+            &self,
+            request: tonic::Request<super::ScheduleCorkRequest>,
+        ) -> Result<tonic::Response<super::ScheduleCorkResponse>, tonic::Status>;
+        #[doc = " Reports the vote and execution progress of a submitted cork"]
+        async fn get_cork_status(
+            &self,
+            request: tonic::Request<super::GetCorkStatusRequest>,
+        ) -> Result<tonic::Response<super::GetCorkStatusResponse>, tonic::Status>;
+        #[doc = " Lists past submissions recorded in the submission journal. Only Submit requests are journaled. With an authorization policy configured, only submissions made by the calling client are listed"]
+        async fn get_submission_history(
+            &self,
+            request: tonic::Request<super::GetSubmissionHistoryRequest>,
+        ) -> Result<tonic::Response<super::GetSubmissionHistoryResponse>, tonic::Status>;
+        #[doc = " Validates and encodes a contract call exactly as Submit would, returning the cork that would be sent without sending it"]
+        async fn preview(
+            &self,
+            request: tonic::Request<super::PreviewRequest>,
+        ) -> Result<tonic::Response<super::PreviewResponse>, tonic::Status>;
+        #[doc = " Decodes the encoded contract call of a cork into the Cellar function it calls and its arguments"]
+        async fn decode(
+            &self,
+            request: tonic::Request<super::DecodeRequest>,
+        ) -> Result<tonic::Response<super::DecodeResponse>, tonic::Status>;
     }
     #[doc = ""]
     #[doc = " Service for handling Cellar contract calls"]
@@ -346,6 +1016,199 @@ pub mod contract_call_server {
                     };
                     Box::pin(fut)
                 }
+                "/steward.v2.ContractCall/SubmitBatch" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubmitBatchSvc<T: ContractCall>(pub Arc<T>);
+                    impl<T: ContractCall> tonic::server::UnaryService<super::SubmitBatchRequest> for SubmitBatchSvc<T> {
+                        type Response = super::SubmitBatchResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubmitBatchRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).submit_batch(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = SubmitBatchSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/steward.v2.ContractCall/ScheduleCork" => {
+                    #[allow(non_camel_case_types)]
+                    struct ScheduleCorkSvc<T: ContractCall>(pub Arc<T>);
+                    impl<T: ContractCall> tonic::server::UnaryService<super::ScheduleCorkRequest>
+                        for ScheduleCorkSvc<T>
+                    {
+                        type Response = super::ScheduleCorkResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ScheduleCorkRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).schedule_cork(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = ScheduleCorkSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/steward.v2.ContractCall/GetCorkStatus" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCorkStatusSvc<T: ContractCall>(pub Arc<T>);
+                    impl<T: ContractCall> tonic::server::UnaryService<super::GetCorkStatusRequest>
+                        for GetCorkStatusSvc<T>
+                    {
+                        type Response = super::GetCorkStatusResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetCorkStatusRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_cork_status(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = GetCorkStatusSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/steward.v2.ContractCall/GetSubmissionHistory" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetSubmissionHistorySvc<T: ContractCall>(pub Arc<T>);
+                    impl<T: ContractCall>
+                        tonic::server::UnaryService<super::GetSubmissionHistoryRequest>
+                        for GetSubmissionHistorySvc<T>
+                    {
+                        type Response = super::GetSubmissionHistoryResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetSubmissionHistoryRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_submission_history(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = GetSubmissionHistorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/steward.v2.ContractCall/Preview" => {
+                    #[allow(non_camel_case_types)]
+                    struct PreviewSvc<T: ContractCall>(pub Arc<T>);
+                    impl<T: ContractCall> tonic::server::UnaryService<super::PreviewRequest> for PreviewSvc<T> {
+                        type Response = super::PreviewResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PreviewRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).preview(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = PreviewSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/steward.v2.ContractCall/Decode" => {
+                    #[allow(non_camel_case_types)]
+                    struct DecodeSvc<T: ContractCall>(pub Arc<T>);
+                    impl<T: ContractCall> tonic::server::UnaryService<super::DecodeRequest> for DecodeSvc<T> {
+                        type Response = super::DecodeResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DecodeRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).decode(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = DecodeSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => Box::pin(async move {
                     Ok(http::Response::builder()
                         .status(200)
@@ -377,3 +1240,150 @@ pub mod contract_call_server {
         const NAME: &'static str = "steward.v2.ContractCall";
     }
 }
+#[doc = r" Generated server implementations."]
+pub mod cellar_query_server {
+    #![allow(unused_variables, dead_code, missing_docs)]
+    use tonic::codegen::*;
+    #[doc = "Generated trait containing gRPC methods that should be implemented for use with CellarQueryServer."]
+    #[async_trait]
+    pub trait CellarQuery: Send + Sync + 'static {
+        #[doc = " Returns a snapshot of the view state of a Cellar"]
+        async fn get_cellar_state(
+            &self,
+            request: tonic::Request<super::GetCellarStateRequest>,
+        ) -> Result<tonic::Response<super::GetCellarStateResponse>, tonic::Status>;
+        #[doc = " Lists the Cellar families this Steward can encode and decode calls for"]
+        async fn get_adapters(
+            &self,
+            request: tonic::Request<super::GetAdaptersRequest>,
+        ) -> Result<tonic::Response<super::GetAdaptersResponse>, tonic::Status>;
+    }
+    #[doc = ""]
+    #[doc = " Service for reading the state of Cellar contracts from Ethereum"]
+    #[derive(Debug)]
+    pub struct CellarQueryServer<T: CellarQuery> {
+        inner: _Inner<T>,
+    }
+    struct _Inner<T>(Arc<T>, Option<tonic::Interceptor>);
+    impl<T: CellarQuery> CellarQueryServer<T> {
+        pub fn new(inner: T) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, None);
+            Self { inner }
+        }
+        pub fn with_interceptor(inner: T, interceptor: impl Into<tonic::Interceptor>) -> Self {
+            let inner = Arc::new(inner);
+            let inner = _Inner(inner, Some(interceptor.into()));
+            Self { inner }
+        }
+    }
+    impl<T, B> Service<http::Request<B>> for CellarQueryServer<T>
+    where
+        T: CellarQuery,
+        B: HttpBody + Send + Sync + 'static,
+        B::Error: Into<StdError> + Send + 'static,
+    {
+        type Response = http::Response<tonic::body::BoxBody>;
+        type Error = Never;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            let inner = self.inner.clone();
+            match req.uri().path() {
+                "/steward.v2.CellarQuery/GetCellarState" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetCellarStateSvc<T: CellarQuery>(pub Arc<T>);
+                    impl<T: CellarQuery> tonic::server::UnaryService<super::GetCellarStateRequest>
+                        for GetCellarStateSvc<T>
+                    {
+                        type Response = super::GetCellarStateResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetCellarStateRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_cellar_state(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = GetCellarStateSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/steward.v2.CellarQuery/GetAdapters" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetAdaptersSvc<T: CellarQuery>(pub Arc<T>);
+                    impl<T: CellarQuery> tonic::server::UnaryService<super::GetAdaptersRequest> for GetAdaptersSvc<T> {
+                        type Response = super::GetAdaptersResponse;
+                        type Future = BoxFuture<tonic::Response<Self::Response>, tonic::Status>;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetAdaptersRequest>,
+                        ) -> Self::Future {
+                            let inner = self.0.clone();
+                            let fut = async move { (*inner).get_adapters(request).await };
+                            Box::pin(fut)
+                        }
+                    }
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let interceptor = inner.1.clone();
+                        let inner = inner.0;
+                        let method = GetAdaptersSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = if let Some(interceptor) = interceptor {
+                            tonic::server::Grpc::with_interceptor(codec, interceptor)
+                        } else {
+                            tonic::server::Grpc::new(codec)
+                        };
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                _ => Box::pin(async move {
+                    Ok(http::Response::builder()
+                        .status(200)
+                        .header("grpc-status", "12")
+                        .header("content-type", "application/grpc")
+                        .body(tonic::body::BoxBody::empty())
+                        .unwrap())
+                }),
+            }
+        }
+    }
+    impl<T: CellarQuery> Clone for CellarQueryServer<T> {
+        fn clone(&self) -> Self {
+            let inner = self.inner.clone();
+            Self { inner }
+        }
+    }
+    impl<T: CellarQuery> Clone for _Inner<T> {
+        fn clone(&self) -> Self {
+            Self(self.0.clone(), self.1.clone())
+        }
+    }
+    impl<T: std::fmt::Debug> std::fmt::Debug for _Inner<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{:?}", self.0)
+        }
+    }
+    impl<T: CellarQuery> tonic::transport::NamedService for CellarQueryServer<T> {
+        const NAME: &'static str = "steward.v2.CellarQuery";
+    }
+}